@@ -0,0 +1,184 @@
+use crate::billing;
+use crate::collection::LoadedSummary;
+use crate::csv;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Smallest threshold, counts below are always suppressed
+pub(crate) const MIN_THRESHOLD: usize = 5;
+
+/// Parses the threshold entered by the user. Empty or invalid input and values below
+/// [`MIN_THRESHOLD`] result in [`MIN_THRESHOLD`], so small counts are never exported by mistake.
+pub(crate) fn threshold(input: &str) -> usize {
+    input
+        .trim()
+        .parse::<usize>()
+        .map_or(MIN_THRESHOLD, |threshold| threshold.max(MIN_THRESHOLD))
+}
+
+/// Number of Meldebestätigungen with the same period, indication, data type, sequencing type
+/// and payer. Contains no case level data.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AggregateRow {
+    pub(crate) period: String,
+    pub(crate) indikationsbereich: String,
+    pub(crate) art_der_daten: String,
+    pub(crate) art_der_sequenzierung: String,
+    pub(crate) kostentraeger: String,
+    /// Number of Meldebestätigungen, none if suppressed
+    pub(crate) count: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Aggregate {
+    /// Counts below this threshold are suppressed
+    pub(crate) threshold: usize,
+    pub(crate) rows: Vec<AggregateRow>,
+}
+
+impl Aggregate {
+    pub(crate) fn new(entries: &[LoadedSummary], threshold: usize) -> Self {
+        let mut grouped = BTreeMap::<_, usize>::new();
+        for loaded in entries {
+            let summary = &loaded.summary;
+            *grouped
+                .entry((
+                    billing::period(summary),
+                    summary.indikationsbereich().to_string(),
+                    summary.art_der_daten().to_string(),
+                    summary.art_der_sequenzierung().to_string(),
+                    summary.kostentraeger().to_string(),
+                ))
+                .or_default() += 1;
+        }
+
+        let rows = grouped
+            .into_iter()
+            .map(
+                |(
+                    (
+                        period,
+                        indikationsbereich,
+                        art_der_daten,
+                        art_der_sequenzierung,
+                        kostentraeger,
+                    ),
+                    count,
+                )| AggregateRow {
+                    period,
+                    indikationsbereich,
+                    art_der_daten,
+                    art_der_sequenzierung,
+                    kostentraeger,
+                    count: (count >= threshold).then_some(count),
+                },
+            )
+            .collect();
+
+        Aggregate { threshold, rows }
+    }
+
+    /// Suppressed counts are given as '<threshold'
+    pub(crate) fn csv(&self) -> String {
+        let mut lines = vec![csv::line(&[
+            "Zeitraum",
+            "Indikationsbereich",
+            "Art der Daten",
+            "Art der Sequenzierung",
+            "Kostenträger",
+            "Anzahl",
+        ])];
+        for row in &self.rows {
+            lines.push(csv::line(&[
+                row.period.clone(),
+                row.indikationsbereich.clone(),
+                row.art_der_daten.clone(),
+                row.art_der_sequenzierung.clone(),
+                row.kostentraeger.clone(),
+                row.count
+                    .map_or_else(|| format!("<{}", self.threshold), |count| count.to_string()),
+            ]));
+        }
+        lines.join("\n") + "\n"
+    }
+
+    pub(crate) fn json(&self) -> Result<String, ()> {
+        serde_json::to_string_pretty(self).map_err(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use submission_summary::RawValue;
+
+    const GKV_1: &str = "Vorgangsnummer,Meldebestaetigung
+0df0f106b45f70a0544c4b35faa2c561b06fa72831d3d1f9cd4be588d74d64e3,IBE+A100000001+A100000001&20240701001&260620431&GRZK00001&0&O&9&1&G&1&1+9+5cf073c6de35abe10121e333baf6a011959bbbb7f3771e9f870876a6bcdac81c
+";
+    const GKV_2: &str = "Vorgangsnummer,Meldebestaetigung
+1e6e092d1100d410eba11d4a4f181885553e9af8ae95f9d6f6cfe4b20b12db11,IBE+A100000002+A100000002&20240702001&260620431&GRZK00001&0&O&9&1&G&1&1+9+04de9585297d5eac3757abc25565d2703981e3db19dbe9efd018c58b12969bae
+";
+    const PKV: &str = "Vorgangsnummer,Meldebestaetigung
+6c0ad45b6cb7c64193b1f32265e8b7355460e8f06b667be212b48d6110a91def,IBE+A100000003+A100000003&20240703001&260620431&GRZK00001&0&O&9&2&G&1&1+9+7366dd771a4730e2448749c1f866bfc936243de1aae58163d2c5254cdaccd180
+";
+
+    fn entries() -> Vec<LoadedSummary> {
+        vec![
+            LoadedSummary::from_test_file("gkv-1.csv", GKV_1),
+            LoadedSummary::from_test_file("gkv-2.csv", GKV_2),
+            LoadedSummary::from_test_file("pkv.csv", PKV),
+        ]
+    }
+
+    #[test]
+    fn test_should_suppress_small_counts() {
+        let aggregate = Aggregate::new(&entries(), 2);
+
+        assert_eq!(
+            aggregate
+                .rows
+                .iter()
+                .map(|row| (row.kostentraeger.as_str(), row.count))
+                .collect::<Vec<_>>(),
+            vec![("GKV", Some(2)), ("PKV", None)]
+        );
+        assert!(aggregate.csv().ends_with(",PKV,<2\n"));
+    }
+
+    #[test]
+    fn test_should_not_export_case_level_data() {
+        let entries = entries();
+        let aggregate = Aggregate::new(&entries, 1);
+
+        for content in [aggregate.csv(), aggregate.json().unwrap_or_default()] {
+            assert!(!content.is_empty());
+            for loaded in &entries {
+                assert!(!content.contains(loaded.summary.tan().raw_value()));
+                assert!(!content.contains(loaded.summary.code().raw_value()));
+                assert!(!content.contains(loaded.summary.hash_wert().raw_value()));
+            }
+        }
+    }
+
+    #[rstest]
+    #[case("", MIN_THRESHOLD)]
+    #[case(" ", MIN_THRESHOLD)]
+    #[case("abc", MIN_THRESHOLD)]
+    #[case("0", MIN_THRESHOLD)]
+    #[case("1", MIN_THRESHOLD)]
+    #[case("10", 10)]
+    fn test_should_parse_threshold_not_below_minimum(#[case] input: &str, #[case] expected: usize) {
+        assert_eq!(threshold(input), expected);
+    }
+
+    #[test]
+    fn test_should_suppress_all_small_counts_without_threshold() {
+        let aggregate = Aggregate::new(&entries(), threshold(""));
+
+        assert!(aggregate.rows.iter().all(|row| row.count.is_none()));
+        assert!(aggregate.csv().ends_with(",PKV,<5\n"));
+    }
+}
@@ -0,0 +1,338 @@
+use crate::collection::LoadedSummary;
+use crate::review::{Review, ReviewStatus};
+use chrono::Local;
+use rusqlite::{Connection, OptionalExtension, Row, params};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use submission_summary::{CSV_HEADER, RawValue, SubmissionSummary};
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS confirmations (
+    tan TEXT PRIMARY KEY NOT NULL,
+    line TEXT NOT NULL,
+    code TEXT NOT NULL,
+    date TEXT NOT NULL,
+    counter TEXT NOT NULL,
+    ik TEXT NOT NULL,
+    datacenter TEXT NOT NULL,
+    typ_der_meldung TEXT NOT NULL,
+    indikationsbereich TEXT NOT NULL,
+    kostentraeger TEXT NOT NULL,
+    art_der_daten TEXT NOT NULL,
+    art_der_sequenzierung TEXT NOT NULL,
+    accepted INTEGER NOT NULL,
+    hash_wert TEXT NOT NULL,
+    valid_hash INTEGER NOT NULL,
+    valid INTEGER NOT NULL,
+    source TEXT NOT NULL,
+    imported_at TEXT NOT NULL,
+    original BLOB NOT NULL
+);
+CREATE TABLE IF NOT EXISTS reviews (
+    tan TEXT PRIMARY KEY NOT NULL,
+    status TEXT NOT NULL,
+    reviewer TEXT NOT NULL,
+    notes TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+)";
+
+/// Meldebestätigung stored within the archive
+#[derive(Clone)]
+pub(crate) struct ArchivedSummary {
+    pub(crate) loaded: LoadedSummary,
+    pub(crate) imported_at: String,
+    pub(crate) review: Review,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ImportResult {
+    Imported,
+    /// A Meldebestätigung with the same TAN has been imported at the given time before
+    AlreadyArchived(String),
+}
+
+/// Local archive of all viewed and imported Meldebestätigungen
+pub(crate) struct Archive {
+    connection: Connection,
+}
+
+impl Archive {
+    /// Default location within the local data directory of the user
+    pub(crate) fn default_path() -> Option<PathBuf> {
+        dirs::data_local_dir().map(|dir| dir.join("submission-summary-viewer").join("archive.db"))
+    }
+
+    pub(crate) fn open(path: &Path) -> Result<Self, ()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|_| ())?;
+        }
+        Self::init(Connection::open(path).map_err(|_| ())?)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn in_memory() -> Result<Self, ()> {
+        Self::init(Connection::open_in_memory().map_err(|_| ())?)
+    }
+
+    fn init(connection: Connection) -> Result<Self, ()> {
+        connection.execute_batch(SCHEMA).map_err(|_| ())?;
+        Ok(Archive { connection })
+    }
+
+    /// Stores the Meldebestätigung together with the content of its file unless one with the same
+    /// TAN has already been archived
+    pub(crate) fn import(&self, loaded: &LoadedSummary) -> Result<ImportResult, ()> {
+        let summary = &loaded.summary;
+        let imported_at = self
+            .connection
+            .query_row(
+                "SELECT imported_at FROM confirmations WHERE tan = ?1",
+                [summary.tan().raw_value()],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| ())?;
+        if let Some(imported_at) = imported_at {
+            return Ok(ImportResult::AlreadyArchived(imported_at));
+        }
+
+        self.connection
+            .execute(
+                "INSERT INTO confirmations VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
+                params![
+                    summary.tan().raw_value(),
+                    summary.line(),
+                    summary.code().raw_value(),
+                    summary.date().raw_value(),
+                    summary.counter().raw_value(),
+                    summary.ik().raw_value(),
+                    summary.datacenter().raw_value(),
+                    summary.typ_der_meldung().raw_value(),
+                    summary.indikationsbereich().raw_value(),
+                    summary.kostentraeger().raw_value(),
+                    summary.art_der_daten().raw_value(),
+                    summary.art_der_sequenzierung().raw_value(),
+                    summary.accepted(),
+                    summary.hash_wert().raw_value(),
+                    summary.valid_hash(),
+                    summary.is_valid(),
+                    loaded.source.display().to_string(),
+                    Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                    loaded.content,
+                ],
+            )
+            .map_err(|_| ())?;
+        Ok(ImportResult::Imported)
+    }
+
+    /// Finds Meldebestätigungen containing the query within TAN, code, date, IK, data node or
+    /// source filename. An empty query returns all Meldebestätigungen.
+    pub(crate) fn search(&self, query: &str) -> Result<Vec<ArchivedSummary>, ()> {
+        let mut statement = self
+            .connection
+            .prepare(
+                "SELECT c.line, c.source, c.imported_at, c.original, r.status, r.reviewer, r.notes, r.updated_at
+                 FROM confirmations c LEFT JOIN reviews r ON r.tan = c.tan
+                 WHERE c.tan LIKE ?1 OR c.code LIKE ?1 OR c.date LIKE ?1 OR c.ik LIKE ?1
+                    OR c.datacenter LIKE ?1 OR c.source LIKE ?1 OR r.notes LIKE ?1
+                 ORDER BY c.date DESC, c.counter DESC",
+            )
+            .map_err(|_| ())?;
+        statement
+            .query_map([format!("%{}%", query.trim())], Self::archived_summary)
+            .map_err(|_| ())?
+            .map(|row| row.map_err(|_| ())?)
+            .collect()
+    }
+
+    pub(crate) fn entries(&self) -> Result<Vec<LoadedSummary>, ()> {
+        Ok(self
+            .search("")?
+            .into_iter()
+            .map(|archived| archived.loaded)
+            .collect())
+    }
+
+    /// Review of the Meldebestätigung with the given TAN, a new one if not reviewed yet
+    pub(crate) fn review(&self, tan: &str) -> Result<Review, ()> {
+        self.connection
+            .query_row(
+                "SELECT status, reviewer, notes, updated_at FROM reviews WHERE tan = ?1",
+                [tan],
+                |row| Self::review_from_row(row, 0),
+            )
+            .optional()
+            .map(Option::unwrap_or_default)
+            .map_err(|_| ())
+    }
+
+    pub(crate) fn set_review(&self, tan: &str, review: &Review) -> Result<(), ()> {
+        self.connection
+            .execute(
+                "INSERT INTO reviews VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT(tan) DO UPDATE SET
+                    status = excluded.status, reviewer = excluded.reviewer,
+                    notes = excluded.notes, updated_at = excluded.updated_at",
+                params![
+                    tan,
+                    review.status.key(),
+                    review.reviewer,
+                    review.notes,
+                    review.updated_at
+                ],
+            )
+            .map(|_| ())
+            .map_err(|_| ())
+    }
+
+    fn review_from_row(row: &Row<'_>, offset: usize) -> rusqlite::Result<Review> {
+        let Some(status) = row.get::<_, Option<String>>(offset)? else {
+            return Ok(Review::default());
+        };
+        Ok(Review {
+            status: ReviewStatus::from_str(&status).unwrap_or_default(),
+            reviewer: row.get(offset + 1)?,
+            notes: row.get(offset + 2)?,
+            updated_at: row.get(offset + 3)?,
+        })
+    }
+
+    fn archived_summary(row: &Row<'_>) -> rusqlite::Result<Result<ArchivedSummary, ()>> {
+        let line = row.get::<_, String>(0)?;
+        let source = row.get::<_, String>(1)?;
+        let imported_at = row.get::<_, String>(2)?;
+        let content = row.get::<_, Vec<u8>>(3)?;
+        let review = Self::review_from_row(row, 4)?;
+        Ok(
+            SubmissionSummary::from_str(&format!("{CSV_HEADER}\n{line}"))
+                .map_err(|_| ())
+                .map(|summary| ArchivedSummary {
+                    loaded: LoadedSummary {
+                        source: PathBuf::from(source),
+                        summary,
+                        content,
+                    },
+                    imported_at,
+                    review,
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JULY: &str = "Vorgangsnummer,Meldebestaetigung
+0ca1fc9961bc4649744a5386217c6c1233ed63eec55def1c2ae8daa929e32f05,IBE+A200000001+A200000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+9c13b4b3d620b4711d33f3f46c2e0a1fca2735534a2726c8220cae53520d9147
+";
+    const AUGUST: &str = "Vorgangsnummer,Meldebestaetigung
+f7d2aa243cc58b4163b1246a3049596e409430977111701e39415d7909858c60,IBE+A200000002+A200000002&20240801001&260620431&KDKK00007&0&O&9&1&C&0&1+9+2b04360cf3b9e9f350fc920fde07cc171ba95a72d0fd177b43f18fe7045b457d
+";
+    /// Same TAN as [`JULY`] submitted again
+    const JULY_RESUBMITTED: &str = "Vorgangsnummer,Meldebestaetigung
+0ca1fc9961bc4649744a5386217c6c1233ed63eec55def1c2ae8daa929e32f05,IBE+A200000001+A200000001&20240801002&260620431&KDKK00007&0&O&9&1&C&0&1+9+b275ce85503f7f888dc7e1de562fe6ee11a93031c45ae5afab8fbf67944c3ee6
+";
+
+    fn july() -> LoadedSummary {
+        LoadedSummary::from_test_file("july.csv", JULY)
+    }
+
+    fn august() -> LoadedSummary {
+        LoadedSummary::from_test_file("august.csv", AUGUST)
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_import_and_restore() {
+        let archive = Archive::in_memory().unwrap();
+        let entry = july();
+
+        assert_eq!(archive.import(&entry), Ok(ImportResult::Imported));
+
+        let archived = archive.search("").unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].loaded.summary, entry.summary);
+        assert_eq!(archived[0].loaded.source, entry.source);
+        assert_eq!(archived[0].loaded.content, entry.content);
+        assert!(!archived[0].imported_at.is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_not_import_same_tan_twice() {
+        let archive = Archive::in_memory().unwrap();
+        archive.import(&july()).unwrap();
+
+        assert!(matches!(
+            archive.import(&LoadedSummary::from_test_file(
+                "resubmitted.csv",
+                JULY_RESUBMITTED
+            )),
+            Ok(ImportResult::AlreadyArchived(_))
+        ));
+        let archived = archive.entries().unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].summary.date().raw_value(), "2024-07-01");
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_store_review() {
+        let archive = Archive::in_memory().unwrap();
+        let entry = july();
+        let tan = entry.summary.tan().raw_value();
+        archive.import(&entry).unwrap();
+        let review = Review {
+            status: ReviewStatus::Disputed,
+            reviewer: "Reviewer".to_string(),
+            notes: "Datum falsch".to_string(),
+            updated_at: "2024-07-02 10:00:00".to_string(),
+        };
+
+        assert_eq!(archive.review(tan), Ok(Review::default()));
+        archive.set_review(tan, &review).unwrap();
+        archive
+            .set_review(
+                tan,
+                &Review {
+                    status: ReviewStatus::Reviewed,
+                    ..review.clone()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(archive.review(tan).unwrap().status, ReviewStatus::Reviewed);
+        let archived = archive.search("falsch").unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].review.reviewer, "Reviewer");
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_search() {
+        let archive = Archive::in_memory().unwrap();
+        let (july, august) = (july(), august());
+        archive.import(&july).unwrap();
+        archive.import(&august).unwrap();
+
+        let tans = |query: &str| {
+            archive
+                .search(query)
+                .unwrap()
+                .into_iter()
+                .map(|archived| archived.loaded.summary.tan().raw_value().to_string())
+                .collect::<Vec<_>>()
+        };
+        let (july, august) = (
+            july.summary.tan().raw_value(),
+            august.summary.tan().raw_value(),
+        );
+
+        assert_eq!(tans("2024-08"), vec![august]);
+        assert_eq!(tans("a200000001"), vec![july]);
+        assert_eq!(tans(&july[..12]), vec![july]);
+        assert_eq!(tans("260620431"), vec![august, july]);
+        assert!(tans("unknown").is_empty());
+    }
+}
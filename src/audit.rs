@@ -0,0 +1,339 @@
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use submission_summary::SubmissionSummary;
+
+/// Hash used as previous hash of the first entry
+const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AuditAction {
+    FileLoaded,
+    Validated,
+    Exported,
+    StatusChanged,
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditAction::FileLoaded => write!(f, "Datei geladen"),
+            AuditAction::Validated => write!(f, "Geprüft"),
+            AuditAction::Exported => write!(f, "Exportiert"),
+            AuditAction::StatusChanged => write!(f, "Status geändert"),
+        }
+    }
+}
+
+/// Entry of the audit log, chained to its predecessor by the hash of the previous entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AuditEntry {
+    pub(crate) index: u64,
+    pub(crate) timestamp: String,
+    pub(crate) user: String,
+    pub(crate) action: AuditAction,
+    pub(crate) details: String,
+    pub(crate) previous_hash: String,
+    pub(crate) hash: String,
+}
+
+impl AuditEntry {
+    fn calculate_hash(&self) -> String {
+        SubmissionSummary::hash(&format!(
+            "{}|{}|{}|{}|{}|{}",
+            self.index, self.timestamp, self.user, self.action, self.details, self.previous_hash
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum AuditError {
+    /// Entry at the given line cannot be read
+    Unreadable(usize),
+    /// Entry at the given line has been edited
+    Edited(usize),
+    /// Entries before the given line have been deleted or reordered
+    Missing(usize),
+    /// Entries from the given index on have been deleted at the end of the log
+    Truncated(u64),
+}
+
+impl Display for AuditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditError::Unreadable(line) => write!(f, "Eintrag in Zeile {line} nicht lesbar"),
+            AuditError::Edited(line) => write!(f, "Eintrag in Zeile {line} wurde verändert"),
+            AuditError::Missing(line) => {
+                write!(f, "Einträge vor Zeile {line} fehlen oder wurden umsortiert")
+            }
+            AuditError::Truncated(index) => {
+                write!(f, "Einträge ab Nummer {index} wurden am Ende entfernt")
+            }
+        }
+    }
+}
+
+/// Index and hash of the last entry written, kept beside the log to detect removed entries at
+/// its end, which the hash chain itself cannot reveal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AuditHead {
+    pub(crate) index: u64,
+    pub(crate) hash: String,
+}
+
+/// Append-only audit log with one JSON entry per line
+pub(crate) struct AuditLog {
+    path: PathBuf,
+}
+
+impl AuditLog {
+    /// Default location within the local data directory of the user
+    pub(crate) fn default_path() -> Option<PathBuf> {
+        dirs::data_local_dir().map(|dir| dir.join("submission-summary-viewer").join("audit.log"))
+    }
+
+    pub(crate) fn new(path: &Path) -> Self {
+        AuditLog {
+            path: path.to_path_buf(),
+        }
+    }
+
+    fn head_path(&self) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".head");
+        PathBuf::from(path)
+    }
+
+    /// Appends an entry while holding an exclusive lock on the log, so concurrent instances
+    /// cannot chain two entries to the same predecessor
+    pub(crate) fn append(&self, action: AuditAction, details: &str) -> Result<(), ()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|_| ())?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|_| ())?;
+        file.lock().map_err(|_| ())?;
+
+        let mut content = String::new();
+        file.read_to_string(&mut content).map_err(|_| ())?;
+        let entry = next_entry(&content, action, details, &user())?;
+        let line = serde_json::to_string(&entry).map_err(|_| ())?;
+        writeln!(file, "{line}").map_err(|_| ())?;
+
+        let head = AuditHead {
+            index: entry.index,
+            hash: entry.hash,
+        };
+        fs::write(
+            self.head_path(),
+            serde_json::to_string(&head).map_err(|_| ())?,
+        )
+        .map_err(|_| ())
+    }
+
+    /// Logs the load of a Meldebestätigung and its validation verdict
+    pub(crate) fn loaded(
+        &self,
+        path: &Path,
+        summary: Option<&SubmissionSummary>,
+    ) -> Result<(), ()> {
+        let source = path.display().to_string();
+        let Some(summary) = summary else {
+            return self.append(AuditAction::FileLoaded, &format!("{source}: nicht lesbar"));
+        };
+        self.append(AuditAction::FileLoaded, &source)?;
+        self.append(
+            AuditAction::Validated,
+            &format!(
+                "TAN {}: {}, Hash-Wert {}, Qualitätskontrolle {}",
+                summary.tan(),
+                if summary.is_valid() {
+                    "gültig"
+                } else {
+                    "ungültig"
+                },
+                if summary.valid_hash() {
+                    "gültig"
+                } else {
+                    "ungültig"
+                },
+                if summary.accepted() {
+                    "bestanden"
+                } else {
+                    "nicht bestanden"
+                },
+            ),
+        )
+    }
+
+    pub(crate) fn verify(&self) -> Result<Result<usize, AuditError>, ()> {
+        let mut content = String::new();
+        match File::open(&self.path) {
+            Ok(mut file) => {
+                file.lock_shared().map_err(|_| ())?;
+                file.read_to_string(&mut content).map_err(|_| ())?;
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(_) => return Err(()),
+        }
+        let head = match fs::read_to_string(self.head_path()) {
+            Ok(head) => Some(serde_json::from_str::<AuditHead>(&head).map_err(|_| ())?),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => None,
+            Err(_) => return Err(()),
+        };
+        Ok(verify(&content, head.as_ref()))
+    }
+}
+
+/// Name of the user running the application
+pub(crate) fn user() -> String {
+    std::env::var("USERNAME")
+        .or_else(|_| std::env::var("USER"))
+        .unwrap_or_default()
+}
+
+fn next_entry(
+    content: &str,
+    action: AuditAction,
+    details: &str,
+    user: &str,
+) -> Result<AuditEntry, ()> {
+    let (index, previous_hash) = match content.lines().rfind(|line| !line.trim().is_empty()) {
+        Some(line) => {
+            let previous = serde_json::from_str::<AuditEntry>(line).map_err(|_| ())?;
+            (previous.index + 1, previous.hash)
+        }
+        None => (0, GENESIS.to_string()),
+    };
+
+    let mut entry = AuditEntry {
+        index,
+        timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        user: user.to_string(),
+        action,
+        details: details.to_string(),
+        previous_hash,
+        hash: String::new(),
+    };
+    entry.hash = entry.calculate_hash();
+    Ok(entry)
+}
+
+/// Verifies the hash chain and returns the number of entries. If given, the last entry written
+/// must still be part of the chain.
+pub(crate) fn verify(content: &str, head: Option<&AuditHead>) -> Result<usize, AuditError> {
+    let mut previous_hash = GENESIS.to_string();
+    let mut count = 0;
+    let mut head_found = head.is_none();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_number = index + 1;
+        let entry = serde_json::from_str::<AuditEntry>(line)
+            .map_err(|_| AuditError::Unreadable(line_number))?;
+        if entry.hash != entry.calculate_hash() {
+            return Err(AuditError::Edited(line_number));
+        }
+        if entry.previous_hash != previous_hash || entry.index != count as u64 {
+            return Err(AuditError::Missing(line_number));
+        }
+        if head.is_some_and(|head| head.index == entry.index && head.hash == entry.hash) {
+            head_found = true;
+        }
+        previous_hash = entry.hash;
+        count += 1;
+    }
+    if !head_found {
+        return Err(AuditError::Truncated(count as u64));
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unwrap_used)]
+    fn log(count: usize) -> Vec<String> {
+        let mut content = String::new();
+        for index in 0..count {
+            let entry = next_entry(
+                &content,
+                AuditAction::FileLoaded,
+                &format!("{index}.csv"),
+                "user",
+            )
+            .unwrap();
+            content += &serde_json::to_string(&entry).unwrap();
+            content += "\n";
+        }
+        content.lines().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_should_verify_chain() {
+        assert_eq!(verify(&log(3).join("\n"), None), Ok(3));
+        assert_eq!(verify("", None), Ok(0));
+    }
+
+    #[test]
+    fn test_should_detect_edited_entry() {
+        let mut lines = log(3);
+        lines[1] = lines[1].replace("1.csv", "x.csv");
+
+        assert_eq!(verify(&lines.join("\n"), None), Err(AuditError::Edited(2)));
+    }
+
+    #[test]
+    fn test_should_detect_deleted_entry() {
+        let mut lines = log(3);
+        lines.remove(1);
+
+        assert_eq!(verify(&lines.join("\n"), None), Err(AuditError::Missing(2)));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_detect_truncated_log() {
+        let mut lines = log(3);
+        let last = serde_json::from_str::<AuditEntry>(&lines[2]).unwrap();
+        let head = AuditHead {
+            index: last.index,
+            hash: last.hash,
+        };
+
+        assert_eq!(verify(&lines.join("\n"), Some(&head)), Ok(3));
+        lines.pop();
+        assert_eq!(
+            verify(&lines.join("\n"), Some(&head)),
+            Err(AuditError::Truncated(2))
+        );
+        assert_eq!(verify("", Some(&head)), Err(AuditError::Truncated(0)));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_append_and_verify_with_head() {
+        let dir = std::env::temp_dir().join(format!("audit-test-{}", std::process::id()));
+        let log = AuditLog::new(&dir.join("audit.log"));
+        log.append(AuditAction::FileLoaded, "a.csv").unwrap();
+        log.append(AuditAction::Exported, "a.pdf").unwrap();
+
+        assert_eq!(log.verify(), Ok(Ok(2)));
+        let content = fs::read_to_string(&log.path).unwrap();
+        fs::write(&log.path, content.lines().next().unwrap()).unwrap();
+        assert_eq!(log.verify(), Ok(Err(AuditError::Truncated(1))));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
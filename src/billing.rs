@@ -0,0 +1,305 @@
+use crate::case_mapping::CaseMapping;
+use crate::collection::LoadedSummary;
+use crate::csv;
+use crate::redaction;
+use crate::tariff::TariffTable;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use submission_summary::{Ik, Kostentraeger, RawValue, SubmissionSummary, TypDerMeldung};
+
+/// Reason a Meldebestätigung is not billable
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Exclusion {
+    QcFailed,
+    Testmeldung,
+    InvalidHash,
+}
+
+impl Display for Exclusion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Exclusion::QcFailed => write!(f, "Qualitätskontrolle nicht bestanden"),
+            Exclusion::Testmeldung => write!(f, "Testmeldung"),
+            Exclusion::InvalidHash => write!(f, "Hash-Wert ungültig"),
+        }
+    }
+}
+
+pub(crate) struct BillingItem<'a> {
+    pub(crate) loaded: &'a LoadedSummary,
+    pub(crate) exclusions: Vec<Exclusion>,
+}
+
+impl BillingItem<'_> {
+    pub(crate) fn is_billable(&self) -> bool {
+        self.exclusions.is_empty()
+    }
+
+    pub(crate) fn reasons(&self) -> String {
+        self.exclusions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// Billing period of a Meldebestätigung, which is the month of its date
+pub(crate) fn period(summary: &SubmissionSummary) -> String {
+    summary.date().raw_value().chars().take(7).collect()
+}
+
+pub(crate) fn classify(entries: &[LoadedSummary]) -> Vec<BillingItem<'_>> {
+    entries
+        .iter()
+        .map(|loaded| {
+            let summary = &loaded.summary;
+            let mut exclusions = vec![];
+            if !summary.accepted() {
+                exclusions.push(Exclusion::QcFailed);
+            }
+            if summary.typ_der_meldung() == &TypDerMeldung::Testmeldung {
+                exclusions.push(Exclusion::Testmeldung);
+            }
+            if !summary.valid_hash() {
+                exclusions.push(Exclusion::InvalidHash);
+            }
+            BillingItem { loaded, exclusions }
+        })
+        .collect()
+}
+
+/// Billable Meldebestätigungen of a Kostenträger, billing period and IK
+pub(crate) struct BillingGroup<'a> {
+    pub(crate) kostentraeger: &'a Kostentraeger,
+    pub(crate) period: String,
+    pub(crate) ik: &'a Ik,
+    pub(crate) items: Vec<&'a BillingItem<'a>>,
+}
+
+pub(crate) fn groups<'a>(items: &'a [BillingItem<'a>]) -> Vec<BillingGroup<'a>> {
+    let mut grouped = BTreeMap::<(&str, String, &str), BillingGroup<'a>>::new();
+    for item in items.iter().filter(|item| item.is_billable()) {
+        let summary = &item.loaded.summary;
+        grouped
+            .entry((
+                summary.kostentraeger().raw_value(),
+                period(summary),
+                summary.ik().raw_value(),
+            ))
+            .or_insert_with(|| BillingGroup {
+                kostentraeger: summary.kostentraeger(),
+                period: period(summary),
+                ik: summary.ik(),
+                items: vec![],
+            })
+            .items
+            .push(item);
+    }
+    grouped.into_values().collect()
+}
+
+/// Billing list of all Meldebestätigungen including the reason of exclusion.
+///
+/// If a fee schedule is given, the expected amount of each billable Meldebestätigung is added.
+/// Internal case numbers are only added if a case mapping is given. If redacted, TANs are masked.
+pub(crate) fn billing_csv(
+    items: &[BillingItem<'_>],
+    table: Option<&TariffTable>,
+    cases: Option<&CaseMapping>,
+    redacted: bool,
+) -> String {
+    let mut header = vec![
+        "Kostenträger",
+        "Abrechnungszeitraum",
+        "IK",
+        "Leistungserbringer",
+        "TAN",
+    ];
+    if cases.is_some() {
+        header.push("Fallnummer");
+    }
+    header.extend([
+        "Datum",
+        "Laufende Nummer",
+        "Typ der Meldung",
+        "Abrechenbar",
+        "Ausschlussgrund",
+        "Erwarteter Betrag",
+        "Datei",
+    ]);
+    let mut lines = vec![csv::line(&header)];
+    let mut items = items.iter().collect::<Vec<_>>();
+    items.sort_by_key(|item| {
+        let summary = &item.loaded.summary;
+        (
+            !item.is_billable(),
+            summary.kostentraeger().raw_value(),
+            period(summary),
+            summary.ik().raw_value(),
+        )
+    });
+    for item in items {
+        let summary = &item.loaded.summary;
+        let mut values = vec![
+            summary.kostentraeger().to_string(),
+            period(summary),
+            summary.ik().raw_value().to_string(),
+            summary.ik().to_string(),
+            redaction::shown(summary.tan().raw_value(), redacted),
+        ];
+        if let Some(cases) = cases {
+            values.push(cases.case_number(summary).unwrap_or_default().to_string());
+        }
+        values.extend([
+            summary.date().to_string(),
+            summary.counter().to_string(),
+            summary.typ_der_meldung().to_string(),
+            if item.is_billable() { "ja" } else { "nein" }.to_string(),
+            item.reasons(),
+            table
+                .filter(|_| item.is_billable())
+                .and_then(|table| table.amount(summary))
+                .map(|amount| amount.to_string())
+                .unwrap_or_default(),
+            item.loaded.shown_name(redacted),
+        ]);
+        lines.push(csv::line(&values));
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const ERSTMELDUNG: &str = "Vorgangsnummer,Meldebestaetigung
+fb54b5209c27d55b06d890f0caeec84bc0810de84ea974d01fb1103e74ae0d20,IBE+A300000001+A300000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+28b36ea89bf6032782c805fc0a14ac1728fc83d70e61f092e411bcaf379322d4
+";
+    const FOLLOW_UP: &str = "Vorgangsnummer,Meldebestaetigung
+69fe60776d7c70806ff4b9136c6b10f4ae1d03f7f7b6d766003285be278deca5,IBE+A300000001+A300000001&20240731001&260620431&KDKK00007&1&O&9&1&C&0&1+9+a50712b4498b34cca968b96e499ca34ca167011ff74982fb8e8f80244b439b14
+";
+    const ERSTMELDUNG_AUGUST: &str = "Vorgangsnummer,Meldebestaetigung
+9c825a68d5ace0c8352a969b6d15201b5ab5745c26643fa62a7b91525cbb5373,IBE+A300000003+A300000003&20240801001&260620431&KDKK00007&0&O&9&1&C&0&1+9+e03bb87b62c53138551322454f9a3d992aee228951cf0e22f12e069859e9cf8d
+";
+    const PKV: &str = "Vorgangsnummer,Meldebestaetigung
+dce005a001441844bd7939c8d5cb2960e5e2c44c5b913e552671d9ec15c1f9a4,IBE+A300000004+A300000004&20240701002&260620431&KDKK00007&0&O&9&2&C&0&1+9+4253d6a26c191a93d61571af74752851748e3522667f36ba35bfaddc752f302f
+";
+    const PKV_BEIHILFE: &str = "Vorgangsnummer,Meldebestaetigung
+3c1aeb4a13063b9882a8478cc8cd3ef73b1f1ec453c8e506aa1ce78bcf39b406,IBE+A300000005+A300000005&20240701003&260620431&KDKK00007&0&O&9&3&C&0&1+9+709bbe86ae4b55ffe63f4e1aaf793e1ed12ff84f5244ea4b66d91b25395fa669
+";
+    const TESTMELDUNG: &str = "Vorgangsnummer,Meldebestaetigung
+eeacbe9716ecc5bf14210f4d678e4a6842311098ad9347dcf04b69180bdbe04a,IBE+A300000006+A300000006&20240701004&260620431&KDKK00007&9&O&9&1&C&0&1+9+1f87f18b167504b998580f1e340ab3948ba79bb42e3108604f469e47842b9c0d
+";
+    const QC_FAILED: &str = "Vorgangsnummer,Meldebestaetigung
+5b9a373e5c3d935453b7d67fef95435849c7e70be0f6620e2cefff8d287b1150,IBE+A300000007+A300000007&20240701005&260620431&KDKK00007&0&O&9&1&C&0&0+9+de14cb8fef6bc8b41635e08e7521eaeab880b475d6a885870e256ee66440fe9d
+";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    #[test]
+    fn test_should_classify() {
+        let entries = vec![loaded(ERSTMELDUNG), loaded(TESTMELDUNG), loaded(QC_FAILED)];
+
+        let items = classify(&entries);
+
+        assert!(items[0].is_billable());
+        assert_eq!(items[1].exclusions, vec![Exclusion::Testmeldung]);
+        assert_eq!(items[2].exclusions, vec![Exclusion::QcFailed]);
+    }
+
+    #[test]
+    fn test_should_group_billable_items() {
+        let entries = vec![
+            loaded(ERSTMELDUNG),
+            loaded(FOLLOW_UP),
+            loaded(ERSTMELDUNG_AUGUST),
+            loaded(PKV),
+            loaded(TESTMELDUNG),
+        ];
+        let items = classify(&entries);
+
+        let groups = groups(&items);
+
+        assert_eq!(
+            groups
+                .iter()
+                .map(|group| (
+                    group.kostentraeger.clone(),
+                    group.period.as_str(),
+                    group.items.len()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (Kostentraeger::Gkv, "2024-07", 2),
+                (Kostentraeger::Gkv, "2024-08", 1),
+                (Kostentraeger::Pkv, "2024-07", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_create_billing_csv() {
+        let entries = vec![loaded(TESTMELDUNG), loaded(PKV_BEIHILFE)];
+        let items = classify(&entries);
+
+        let csv = billing_csv(&items, None, None, false);
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("PKV/Beihilfe,2024-07,260620431,"));
+        assert!(lines[1].contains(&format!(
+            ",{},2024-07-01,003,Erstmeldung,ja,,,",
+            entries[1].summary.tan()
+        )));
+        assert!(lines[2].contains(&format!(
+            ",{},2024-07-01,004,Testmeldung,nein,Testmeldung,,",
+            entries[0].summary.tan()
+        )));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_add_expected_amount_to_billing_csv() {
+        let table = TariffTable::from_str("Art der Daten;Art der Sequenzierung;Indikationsbereich;Typ der Meldung;Gültig ab;Gültig bis;Betrag\n*;*;*;*;2024-01-01;;1000").unwrap();
+        let entries = vec![loaded(ERSTMELDUNG), loaded(TESTMELDUNG)];
+        let items = classify(&entries);
+
+        let csv = billing_csv(&items, Some(&table), None, false);
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert!(lines[1].contains(",ja,,\"1000,00 €\","));
+        assert!(lines[2].contains(",nein,Testmeldung,,"));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_add_case_numbers_only_if_requested() {
+        let entries = vec![loaded(ERSTMELDUNG)];
+        let tan = entries[0].summary.tan().raw_value();
+        let cases =
+            CaseMapping::from_str(&format!("TAN oder Code;Fallnummer\n{tan};MTB-1")).unwrap();
+        let items = classify(&entries);
+
+        assert!(!billing_csv(&items, None, None, false).contains("MTB-1"));
+        let csv = billing_csv(&items, None, Some(&cases), false);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert!(lines[0].contains(",TAN,Fallnummer,Datum,"));
+        assert!(lines[1].contains(&format!(",{tan},MTB-1,2024-07-01,")));
+    }
+
+    #[test]
+    fn test_should_redact_tan_and_file_name_in_billing_csv() {
+        let entries = vec![loaded(ERSTMELDUNG)];
+        let items = classify(&entries);
+
+        let csv = billing_csv(&items, None, None, true);
+
+        assert!(!csv.contains(entries[0].summary.tan().raw_value()));
+        assert!(csv.contains(",fb54…0d20,2024-07-01,001,Erstmeldung,ja,"));
+        assert!(csv.ends_with(",meld….csv\n"));
+    }
+}
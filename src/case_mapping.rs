@@ -0,0 +1,176 @@
+use crate::csv;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use submission_summary::{RawValue, SubmissionSummary};
+
+/// Identifies encrypted mapping files
+const MAGIC: &[u8] = b"SSVCASES1";
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+/// Header line of the mapping file
+const HEADER: &str = "TAN oder Code;Fallnummer";
+
+/// Mapping from TAN or code to the internal case number, e.g. the MTB number
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct CaseMapping(BTreeMap<String, String>);
+
+impl CaseMapping {
+    /// Reads and decrypts the mapping file using the given passphrase
+    pub(crate) fn unlock(path: &Path, passphrase: &str) -> Result<Self, ()> {
+        let content = fs::read(path).map_err(|_| ())?;
+        CaseMapping::from_str(&decrypt(&content, passphrase)?)
+    }
+
+    /// Case number of the Meldebestätigung, looked up by TAN first and then by code
+    pub(crate) fn case_number(&self, summary: &SubmissionSummary) -> Option<&str> {
+        self.0
+            .get(summary.tan().raw_value())
+            .or_else(|| self.0.get(summary.code().raw_value()))
+            .map(String::as_str)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl FromStr for CaseMapping {
+    type Err = ();
+
+    /// Parses ';' separated lines of TAN or code and case number following the header line.
+    /// Every TAN or code may only be given once.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        if !lines
+            .next()
+            .is_some_and(|line| csv::is_header(line, HEADER, ';'))
+        {
+            return Err(());
+        }
+
+        let mut mapping = BTreeMap::new();
+        for line in lines.map(str::trim).filter(|line| !line.is_empty()) {
+            let Some((key, case_number)) = line.split_once(';') else {
+                return Err(());
+            };
+            let (key, case_number) = (key.trim(), case_number.trim());
+            if key.is_empty() || case_number.is_empty() {
+                return Err(());
+            }
+            if mapping
+                .insert(key.to_string(), case_number.to_string())
+                .is_some()
+            {
+                return Err(());
+            }
+        }
+        Ok(CaseMapping(mapping))
+    }
+}
+
+fn key(passphrase: &str, salt: &[u8]) -> Result<Key<Aes256Gcm>, ()> {
+    let mut key = Key::<Aes256Gcm>::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|_| ())?;
+    Ok(key)
+}
+
+/// Encrypts the content using a key derived from the passphrase by Argon2
+pub(crate) fn encrypt(content: &str, passphrase: &str) -> Result<Vec<u8>, ()> {
+    let mut salt = [0u8; SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = Aes256Gcm::new(&key(passphrase, &salt)?)
+        .encrypt(&nonce, content.as_bytes())
+        .map_err(|_| ())?;
+
+    Ok([MAGIC, &salt, &nonce, &ciphertext].concat())
+}
+
+/// Decrypts the content, fails on a wrong passphrase or modified content
+pub(crate) fn decrypt(content: &[u8], passphrase: &str) -> Result<String, ()> {
+    let content = content.strip_prefix(MAGIC).ok_or(())?;
+    if content.len() < SALT_LENGTH + NONCE_LENGTH {
+        return Err(());
+    }
+    let (salt, content) = content.split_at(SALT_LENGTH);
+    let (nonce, ciphertext) = content.split_at(NONCE_LENGTH);
+    let plaintext = Aes256Gcm::new(&key(passphrase, salt)?)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| ())?;
+
+    String::from_utf8(plaintext).map_err(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::LoadedSummary;
+
+    const BY_TAN: &str = "Vorgangsnummer,Meldebestaetigung
+67a84c5a5e59c9db3fa1411888bc0d36badee61841a1be9a176629ddf325ed7a,IBE+A400000001+A400000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+2eac3195d3b7db22672190b7ab2c76de075402a9a03ede539542dfb85047002c
+";
+    const BY_CODE: &str = "Vorgangsnummer,Meldebestaetigung
+a1a2741208e66b13151f8c21dd9c33b14039a8d546e21646204d57f002269c10,IBE+A400000002+A400000002&20240701002&260620431&KDKK00007&0&O&9&1&C&0&1+9+ef99575138b9ce4f99ebd38146e3b5e5b44e255fb3dd4c434e69173a1fc02278
+";
+    const UNMAPPED: &str = "Vorgangsnummer,Meldebestaetigung
+de18fccb1dede2cfc91d0bddb5393d25e2ac183f72dff83f072133a835159fd3,IBE+A400000003+A400000003&20240701003&260620431&KDKK00007&0&O&9&1&C&0&1+9+75e7ab411dc632d875843e87d07a24d2af41e221450e16f64b76778afee3b6ea
+";
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_decrypt_encrypted_content() {
+        let content = "TAN oder Code;Fallnummer\nT1;MTB-2024-001\n";
+        let encrypted = encrypt(content, "geheim").unwrap();
+
+        assert!(!encrypted.windows(3).any(|window| window == b"MTB"));
+        assert_eq!(decrypt(&encrypted, "geheim"), Ok(content.to_string()));
+        assert_eq!(decrypt(&encrypted, "falsch"), Err(()));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_find_case_number_by_tan_or_code() {
+        let by_tan = LoadedSummary::from_test_file("by-tan.csv", BY_TAN);
+        let mapping = CaseMapping::from_str(&format!(
+            "TAN oder Code;Fallnummer\n{};MTB-1\nA400000002;MTB-2\n",
+            by_tan.summary.tan()
+        ))
+        .unwrap();
+        let case_number = |content: &str| {
+            let loaded = LoadedSummary::from_test_file("meldebestaetigung.csv", content);
+            mapping
+                .case_number(&loaded.summary)
+                .map(ToString::to_string)
+        };
+
+        assert_eq!(case_number(BY_TAN), Some("MTB-1".to_string()));
+        assert_eq!(case_number(BY_CODE), Some("MTB-2".to_string()));
+        assert_eq!(case_number(UNMAPPED), None);
+        assert_eq!(
+            CaseMapping::from_str("TAN oder Code;Fallnummer\nT1"),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn test_should_not_parse_mapping_without_header() {
+        assert_eq!(CaseMapping::from_str("T1;MTB-1\nT2;MTB-2\n"), Err(()));
+        assert_eq!(CaseMapping::from_str(""), Err(()));
+    }
+
+    #[test]
+    fn test_should_not_parse_mapping_with_duplicate_key() {
+        assert_eq!(
+            CaseMapping::from_str("TAN oder Code;Fallnummer\nT1;MTB-1\nT1;MTB-2\n"),
+            Err(())
+        );
+    }
+}
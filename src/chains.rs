@@ -0,0 +1,229 @@
+use crate::collection::LoadedSummary;
+use chrono::{Days, NaiveDate};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use submission_summary::{Ik, RawValue, TypDerMeldung};
+
+/// Meldebestätigungen of a single case ordered by date and counter
+pub(crate) struct Chain<'a> {
+    pub(crate) ik: &'a Ik,
+    pub(crate) code: &'a str,
+    pub(crate) entries: Vec<ChainEntry<'a>>,
+}
+
+impl Chain<'_> {
+    pub(crate) fn has_findings(&self) -> bool {
+        self.entries.iter().any(|entry| entry.finding.is_some())
+    }
+}
+
+pub(crate) struct ChainEntry<'a> {
+    pub(crate) loaded: &'a LoadedSummary,
+    pub(crate) finding: Option<ChainFinding>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ChainFinding {
+    /// Follow-Up, Nachmeldung or Korrektur without preceding Erstmeldung
+    MissingErstmeldung,
+    /// Erstmeldung without Follow-Up although it was expected up to the given date
+    MissingFollowUp(NaiveDate),
+}
+
+impl Display for ChainFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainFinding::MissingErstmeldung => write!(f, "Keine vorausgehende Erstmeldung"),
+            ChainFinding::MissingFollowUp(due) => {
+                write!(f, "Kein Follow-Up, erwartet bis {}", due.format("%d.%m.%Y"))
+            }
+        }
+    }
+}
+
+/// Groups Meldebestätigungen into chains by IK and code of the case.
+///
+/// Erstmeldungen are expected to be followed by a Follow-Up within `follow_up_days`,
+/// which is checked against `today`.
+pub(crate) fn chains(
+    entries: &[LoadedSummary],
+    today: NaiveDate,
+    follow_up_days: u64,
+) -> Vec<Chain<'_>> {
+    let mut grouped = BTreeMap::<(&str, &str), Vec<&LoadedSummary>>::new();
+    for loaded in entries {
+        grouped
+            .entry((
+                loaded.summary.ik().raw_value(),
+                loaded.summary.code().raw_value(),
+            ))
+            .or_default()
+            .push(loaded);
+    }
+
+    let mut chains = grouped
+        .into_values()
+        .map(|mut loaded| {
+            loaded.sort_by_key(|loaded| {
+                (
+                    loaded.summary.parsed_date(),
+                    loaded.summary.parsed_counter(),
+                )
+            });
+
+            let entries = loaded
+                .iter()
+                .enumerate()
+                .map(|(index, current)| ChainEntry {
+                    loaded: current,
+                    finding: finding(
+                        &loaded[..index],
+                        current,
+                        &loaded[index + 1..],
+                        today,
+                        follow_up_days,
+                    ),
+                })
+                .collect();
+
+            Chain {
+                ik: loaded[0].summary.ik(),
+                code: loaded[0].summary.code().raw_value(),
+                entries,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    chains.sort_by_key(|chain| chain.entries[0].loaded.summary.parsed_date());
+    chains
+}
+
+fn finding(
+    previous: &[&LoadedSummary],
+    current: &LoadedSummary,
+    following: &[&LoadedSummary],
+    today: NaiveDate,
+    follow_up_days: u64,
+) -> Option<ChainFinding> {
+    let is = |loaded: &&LoadedSummary, typ: &TypDerMeldung| loaded.summary.typ_der_meldung() == typ;
+
+    match current.summary.typ_der_meldung() {
+        TypDerMeldung::FollowUp | TypDerMeldung::Nachmeldung | TypDerMeldung::Korrektur
+            if !previous
+                .iter()
+                .any(|loaded| is(loaded, &TypDerMeldung::Erstmeldung)) =>
+        {
+            Some(ChainFinding::MissingErstmeldung)
+        }
+        TypDerMeldung::Erstmeldung
+            if !following
+                .iter()
+                .any(|loaded| is(loaded, &TypDerMeldung::FollowUp)) =>
+        {
+            let due = current
+                .summary
+                .parsed_date()?
+                .checked_add_days(Days::new(follow_up_days))?;
+            (due < today).then_some(ChainFinding::MissingFollowUp(due))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERSTMELDUNG: &str = "Vorgangsnummer,Meldebestaetigung
+660551cc34837aad15132bf8b12847e41449bd321374b465c74c373b851bc778,IBE+A500000001+A500000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+05c6f5a93c7d1fc9a64655abb9fcec3e05f41f9ded315dda6e9af489199f5e44
+";
+    const KORREKTUR: &str = "Vorgangsnummer,Meldebestaetigung
+c6141461048c8afd5f310b136181f2c8c4a4fb70acadfea79412928991468a4b,IBE+A500000001+A500000001&20240701002&260620431&KDKK00007&3&O&9&1&C&0&1+9+5d868e3bbf4fff26b98b39238cf490fb9106d23ece08c4206ab41c1f4c1ef311
+";
+    const FOLLOW_UP: &str = "Vorgangsnummer,Meldebestaetigung
+d2cfe79d6de5381a9da1534813e115c54c93e66c7fb8591a581db45ad6c3dcf0,IBE+A500000001+A500000001&20240801001&260620431&KDKK00007&1&O&9&1&C&0&1+9+9389405e7ca51a8a4afc12d233130f9f512b1a2728a802ef4ce7c2a6bdb4f35c
+";
+    const KORREKTUR_FOLLOW_UP: &str = "Vorgangsnummer,Meldebestaetigung
+c4fc20b594d30c3b2531406ae837a7f918df78c19feae76ff7413b3293a983ce,IBE+A500000001+A500000001&20240802001&260620431&KDKK00007&3&O&9&1&C&0&1+9+d4f4f4efafd4b51f4b94e7384a54f10e668d737ba9521c2123b210a973c8120b
+";
+    const OTHER_CASE: &str = "Vorgangsnummer,Meldebestaetigung
+2060bebdb5a0cf6d418047020beec9e9bfbefa18f050269aff79264065a52727,IBE+A500000002+A500000002&20240702001&260620431&KDKK00007&0&O&9&1&C&0&1+9+7de62c09a90d2a0c3b96f1527db46fbe4997a11e84a1d87db3a1c912c6dc7b1d
+";
+    const ERSTMELDUNG_JANUARY: &str = "Vorgangsnummer,Meldebestaetigung
+f81109b6c6d25eb9ab3a6f52f9e4ef08b151795a8366f15bca7feea713f9f382,IBE+A500000001+A500000001&20240101001&260620431&KDKK00007&0&O&9&1&C&0&1+9+8b32efa9003dcb069453f6412db45cb65074bc93aeccd7e68068ae6fa8621f52
+";
+    const OTHER_CASE_DECEMBER: &str = "Vorgangsnummer,Meldebestaetigung
+fd4b5f3fca4ec98e31ce10be873b30d4884dcd40a4ebc837b026d6cff48c6356,IBE+A500000002+A500000002&20241201001&260620431&KDKK00007&0&O&9&1&C&0&1+9+afec500f14243f47352d2065f9277eb76d0ec66b46f1f18eb67c57a5ea30f9ae
+";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap_or_default()
+    }
+
+    #[test]
+    fn test_should_group_and_order_chain() {
+        let entries = vec![
+            loaded(FOLLOW_UP),
+            loaded(OTHER_CASE),
+            loaded(KORREKTUR),
+            loaded(ERSTMELDUNG),
+        ];
+
+        let chains = chains(&entries, today(), 365);
+
+        assert_eq!(chains.len(), 2);
+        assert_eq!(chains[0].code, "A500000001");
+        assert_eq!(
+            chains[0]
+                .entries
+                .iter()
+                .map(|entry| entry.loaded.summary.typ_der_meldung().clone())
+                .collect::<Vec<_>>(),
+            vec![
+                TypDerMeldung::Erstmeldung,
+                TypDerMeldung::Korrektur,
+                TypDerMeldung::FollowUp
+            ]
+        );
+        assert!(!chains[0].has_findings());
+        assert_eq!(chains[1].code, "A500000002");
+    }
+
+    #[test]
+    fn test_should_find_missing_erstmeldung() {
+        let entries = vec![loaded(FOLLOW_UP), loaded(KORREKTUR_FOLLOW_UP)];
+
+        let chains = chains(&entries, today(), 365);
+
+        assert_eq!(
+            chains[0]
+                .entries
+                .iter()
+                .map(|entry| entry.finding.as_ref())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(&ChainFinding::MissingErstmeldung),
+                Some(&ChainFinding::MissingErstmeldung)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_find_missing_follow_up() {
+        let entries = vec![loaded(ERSTMELDUNG_JANUARY), loaded(OTHER_CASE_DECEMBER)];
+
+        let chains = chains(&entries, today(), 90);
+
+        assert_eq!(
+            chains[0].entries[0].finding,
+            Some(ChainFinding::MissingFollowUp(
+                NaiveDate::from_ymd_opt(2024, 3, 31).unwrap_or_default()
+            ))
+        );
+        assert_eq!(chains[1].entries[0].finding, None);
+    }
+}
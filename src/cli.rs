@@ -0,0 +1,398 @@
+use crate::archive::{Archive, ImportResult};
+use crate::audit::{AuditAction, AuditLog};
+use crate::case_mapping::{self, CaseMapping};
+use crate::collection::Collection;
+use crate::csv;
+#[cfg(feature = "kafka")]
+use crate::kafka::{self, KafkaConfig};
+use crate::package;
+use crate::redaction;
+use crate::server;
+use chrono::Local;
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use submission_summary::RawValue;
+
+/// Views and archives MV §64e Meldebestätigungen. Starts the GUI if no command is given.
+#[derive(Parser)]
+#[command(version, about)]
+pub(crate) struct Cli {
+    /// Archive file to be used instead of the default location
+    #[arg(long, global = true)]
+    pub(crate) archive: Option<PathBuf>,
+    /// Audit log file to be used instead of the default location
+    #[arg(long, global = true)]
+    pub(crate) audit_log: Option<PathBuf>,
+    #[command(subcommand)]
+    pub(crate) command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// Imports Meldebestätigungen from files or directories into the local archive
+    Import { paths: Vec<PathBuf> },
+    /// Searches the local archive by TAN, code, date, IK, data node or filename
+    Search {
+        query: Option<String>,
+        /// Masks all but the first and last four characters of TAN and code
+        #[arg(long)]
+        redact: bool,
+    },
+    /// Verifies the hash chain of the audit log to detect edited or deleted entries
+    VerifyAuditLog,
+    /// Encrypts a ';' separated file of TAN or code and internal case number. The passphrase is
+    /// entered twice on the terminal without being shown.
+    EncryptCaseMapping { input: PathBuf, output: PathBuf },
+    /// Exports Meldebestätigungen from files or directories, or all archived ones of a period,
+    /// as ZIP archival package
+    ExportPackage {
+        /// ZIP file to be written
+        #[arg(long)]
+        output: PathBuf,
+        /// Period of archived Meldebestätigungen to be exported, e.g. '2024' or '2024-07'
+        #[arg(long, conflicts_with = "paths", required_unless_present = "paths")]
+        period: Option<String>,
+        paths: Vec<PathBuf>,
+    },
+    /// Verifies the SHA-256 checksums of all members of an archival package
+    VerifyPackage { path: PathBuf },
+    /// Starts an HTTP server on localhost validating Meldebestätigungen sent by POST to
+    /// /validate. The parsed content and validation report are returned as JSON.
+    Serve {
+        #[arg(long, default_value_t = 8064)]
+        port: u16,
+    },
+    /// Consumes Meldebestätigung lines from a Kafka topic and publishes the parsed and validated
+    /// result as JSON. Unparseable input is published to the dead-letter topic.
+    #[cfg(feature = "kafka")]
+    Kafka {
+        #[arg(long, default_value = "localhost:9092")]
+        bootstrap_servers: String,
+        #[arg(long, default_value = "submission-summary-viewer")]
+        group_id: String,
+        #[arg(long)]
+        input_topic: String,
+        #[arg(long)]
+        output_topic: String,
+        #[arg(long)]
+        dead_letter_topic: String,
+    },
+}
+
+impl Cli {
+    pub(crate) fn run(self) -> ExitCode {
+        let Some(audit_log) = self
+            .audit_log
+            .or_else(AuditLog::default_path)
+            .map(|path| AuditLog::new(&path))
+        else {
+            eprintln!("Audit-Log nicht gefunden");
+            return ExitCode::FAILURE;
+        };
+        match &self.command {
+            Some(Command::VerifyAuditLog) => return verify_audit_log(&audit_log),
+            Some(Command::EncryptCaseMapping { input, output }) => {
+                return encrypt_case_mapping(input, output);
+            }
+            Some(Command::VerifyPackage { path }) => return verify_package(path),
+            Some(Command::Serve { port }) => return serve(*port),
+            #[cfg(feature = "kafka")]
+            Some(Command::Kafka {
+                bootstrap_servers,
+                group_id,
+                input_topic,
+                output_topic,
+                dead_letter_topic,
+            }) => {
+                return kafka(&KafkaConfig {
+                    bootstrap_servers: bootstrap_servers.clone(),
+                    group_id: group_id.clone(),
+                    input_topic: input_topic.clone(),
+                    output_topic: output_topic.clone(),
+                    dead_letter_topic: dead_letter_topic.clone(),
+                });
+            }
+            _ => {}
+        }
+
+        let Some(path) = self.archive.or_else(Archive::default_path) else {
+            eprintln!("Archiv nicht gefunden");
+            return ExitCode::FAILURE;
+        };
+        let Ok(archive) = Archive::open(&path) else {
+            eprintln!("Archiv {} konnte nicht geöffnet werden", path.display());
+            return ExitCode::FAILURE;
+        };
+
+        match self.command {
+            Some(Command::Import { paths }) => import(&archive, &audit_log, &paths),
+            Some(Command::Search { query, redact }) => {
+                search(&archive, &query.unwrap_or_default(), redact)
+            }
+            Some(Command::ExportPackage {
+                output,
+                period,
+                paths,
+            }) => export_package(&archive, &audit_log, &output, period.as_deref(), &paths),
+            Some(
+                Command::VerifyAuditLog
+                | Command::EncryptCaseMapping { .. }
+                | Command::VerifyPackage { .. }
+                | Command::Serve { .. },
+            )
+            | None => ExitCode::SUCCESS,
+            #[cfg(feature = "kafka")]
+            Some(Command::Kafka { .. }) => ExitCode::SUCCESS,
+        }
+    }
+}
+
+fn import(archive: &Archive, audit_log: &AuditLog, paths: &[PathBuf]) -> ExitCode {
+    let mut collection = Collection::default();
+    for path in paths {
+        collection.add_path(path);
+    }
+
+    let mut failed = !collection.errors.is_empty();
+    for (path, error) in &collection.errors {
+        eprintln!("Fehler beim Lesen von {}: {error}", path.display());
+        if audit_log.loaded(path, None).is_err() {
+            failed = true;
+            eprintln!("Audit-Log konnte nicht geschrieben werden");
+        }
+    }
+    for loaded in &collection.entries {
+        if audit_log
+            .loaded(&loaded.source, Some(&loaded.summary))
+            .is_err()
+        {
+            failed = true;
+            eprintln!("Audit-Log konnte nicht geschrieben werden");
+        }
+        match archive.import(loaded) {
+            Ok(ImportResult::Imported) => println!("Importiert: {}", loaded.source.display()),
+            Ok(ImportResult::AlreadyArchived(imported_at)) => eprintln!(
+                "Warnung: TAN {} aus {} wurde bereits am {imported_at} archiviert",
+                loaded.summary.tan(),
+                loaded.source.display()
+            ),
+            Err(()) => {
+                failed = true;
+                eprintln!("Fehler beim Archivieren von {}", loaded.source.display());
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify_audit_log(audit_log: &AuditLog) -> ExitCode {
+    match audit_log.verify() {
+        Ok(Ok(count)) => {
+            println!("Audit-Log mit {count} Einträgen ist unverändert");
+            ExitCode::SUCCESS
+        }
+        Ok(Err(error)) => {
+            eprintln!("Audit-Log ist fehlerhaft: {error}");
+            ExitCode::FAILURE
+        }
+        Err(()) => {
+            eprintln!("Audit-Log konnte nicht gelesen werden");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn encrypt_case_mapping(input: &Path, output: &Path) -> ExitCode {
+    let Ok(content) = fs::read_to_string(input) else {
+        eprintln!("{} konnte nicht gelesen werden", input.display());
+        return ExitCode::FAILURE;
+    };
+    let Ok(mapping) = CaseMapping::from_str(&content) else {
+        eprintln!("{} enthält keine gültige Zuordnung", input.display());
+        return ExitCode::FAILURE;
+    };
+
+    let Ok(passphrase) = rpassword::prompt_password("Passphrase: ") else {
+        eprintln!("Passphrase konnte nicht gelesen werden");
+        return ExitCode::FAILURE;
+    };
+    if passphrase.trim().is_empty() {
+        eprintln!("Keine Passphrase angegeben");
+        return ExitCode::FAILURE;
+    }
+    if !rpassword::prompt_password("Passphrase wiederholen: ")
+        .is_ok_and(|repeated| repeated == passphrase)
+    {
+        eprintln!("Passphrasen stimmen nicht überein");
+        return ExitCode::FAILURE;
+    }
+
+    match case_mapping::encrypt(&content, &passphrase) {
+        Ok(encrypted) if fs::write(output, &encrypted).is_ok() => {
+            println!("{} Fallnummer(n) verschlüsselt", mapping.len());
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{} konnte nicht geschrieben werden", output.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn export_package(
+    archive: &Archive,
+    audit_log: &AuditLog,
+    output: &Path,
+    period: Option<&str>,
+    paths: &[PathBuf],
+) -> ExitCode {
+    let entries = if let Some(period) = period {
+        let Ok(entries) = archive.entries() else {
+            eprintln!("Fehler beim Lesen des Archivs");
+            return ExitCode::FAILURE;
+        };
+        entries
+            .into_iter()
+            .filter(|loaded| loaded.summary.date().raw_value().starts_with(period))
+            .collect::<Vec<_>>()
+    } else {
+        let mut collection = Collection::default();
+        for path in paths {
+            collection.add_path(path);
+        }
+        if let Some((path, error)) = collection.errors.first() {
+            eprintln!("Fehler beim Lesen von {}: {error}", path.display());
+            return ExitCode::FAILURE;
+        }
+        collection.entries
+    };
+    if entries.is_empty() {
+        eprintln!("Keine Meldebestätigungen gefunden");
+        return ExitCode::FAILURE;
+    }
+
+    let created_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    match package::package(&entries, &created_at) {
+        Ok(content) if fs::write(output, &content).is_ok() => {
+            println!(
+                "{} Meldebestätigung(en) exportiert nach {}",
+                entries.len(),
+                output.display()
+            );
+            if audit_log
+                .append(AuditAction::Exported, &output.display().to_string())
+                .is_err()
+            {
+                eprintln!("Audit-Log konnte nicht geschrieben werden");
+                return ExitCode::FAILURE;
+            }
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{} konnte nicht geschrieben werden", output.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn verify_package(path: &Path) -> ExitCode {
+    let Ok(content) = fs::read(path) else {
+        eprintln!("{} konnte nicht gelesen werden", path.display());
+        return ExitCode::FAILURE;
+    };
+    match package::verify(&content) {
+        Ok(count) => {
+            println!("Paket mit {count} Dateien ist unverändert");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Paket ist fehlerhaft: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(port: u16) -> ExitCode {
+    println!("Server gestartet auf http://127.0.0.1:{port}/validate");
+    if server::serve(port).is_ok() {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("Server konnte auf Port {port} nicht gestartet werden");
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(feature = "kafka")]
+fn kafka(config: &KafkaConfig) -> ExitCode {
+    println!(
+        "Verarbeite Topic {} auf {}",
+        config.input_topic, config.bootstrap_servers
+    );
+    match kafka::run(config) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Kafka-Fehler: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn search(archive: &Archive, query: &str, redacted: bool) -> ExitCode {
+    let Ok(results) = archive.search(query) else {
+        eprintln!("Fehler beim Durchsuchen des Archivs");
+        return ExitCode::FAILURE;
+    };
+
+    println!(
+        "{}",
+        csv::line(&[
+            "Datum",
+            "Laufende Nummer",
+            "TAN",
+            "Code",
+            "IK",
+            "Datenknoten",
+            "Typ der Meldung",
+            "Prüfergebnis",
+            "Datei",
+            "Importiert",
+            "Status",
+            "Bearbeiter/in",
+            "Notizen",
+        ])
+    );
+    for archived in results {
+        let summary = &archived.loaded.summary;
+        println!(
+            "{}",
+            csv::line(&[
+                summary.date().raw_value(),
+                summary.counter().raw_value(),
+                &redaction::shown(summary.tan().raw_value(), redacted),
+                &redaction::shown(summary.code().raw_value(), redacted),
+                summary.ik().raw_value(),
+                summary.datacenter().raw_value(),
+                &summary.typ_der_meldung().to_string(),
+                if summary.is_valid() {
+                    "gültig"
+                } else {
+                    "ungültig"
+                },
+                &redaction::shown_path(&archived.loaded.source, redacted),
+                &archived.imported_at,
+                &archived.review.status.to_string(),
+                &archived.review.reviewer,
+                &archived.review.notes,
+            ])
+        );
+    }
+    ExitCode::SUCCESS
+}
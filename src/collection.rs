@@ -0,0 +1,158 @@
+use crate::redaction;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use submission_summary::{ParseError, SubmissionSummary};
+
+/// Meldebestätigung together with the file it has been read from
+#[derive(Clone)]
+pub(crate) struct LoadedSummary {
+    pub(crate) source: PathBuf,
+    pub(crate) summary: SubmissionSummary,
+    /// Bytes of the file as they have been parsed
+    pub(crate) content: Vec<u8>,
+}
+
+impl LoadedSummary {
+    /// Reads and parses a Meldebestätigung file, keeping the bytes that have been parsed
+    pub(crate) fn read(path: &Path) -> Result<Self, LoadError> {
+        let content = fs::read(path).map_err(|_| LoadError::Unreadable)?;
+        let text = str::from_utf8(&content).map_err(|_| LoadError::Unreadable)?;
+        let summary = SubmissionSummary::from_str(text).map_err(LoadError::Invalid)?;
+        Ok(LoadedSummary {
+            source: path.to_path_buf(),
+            summary,
+            content,
+        })
+    }
+
+    pub(crate) fn source_name(&self) -> String {
+        file_name(&self.source)
+    }
+
+    /// Name of the source file or, if redacted, its masked form
+    pub(crate) fn shown_name(&self, redacted: bool) -> String {
+        redaction::shown(&self.source_name(), redacted)
+    }
+}
+
+/// Name of the file or, lacking one, the whole path
+pub(crate) fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().to_string(),
+    )
+}
+
+/// Reason why a file could not be loaded
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum LoadError {
+    Unreadable,
+    Invalid(ParseError),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Unreadable => write!(f, "Datei nicht lesbar"),
+            LoadError::Invalid(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// Meldebestätigungen loaded at once to be analysed together
+#[derive(Default)]
+pub(crate) struct Collection {
+    pub(crate) entries: Vec<LoadedSummary>,
+    /// Files that could not be loaded and the reason
+    pub(crate) errors: Vec<(PathBuf, LoadError)>,
+}
+
+impl Collection {
+    /// Adds a file or all CSV files within a directory
+    pub(crate) fn add_path(&mut self, path: &Path) {
+        if path.is_dir() {
+            let Ok(dir) = fs::read_dir(path) else {
+                self.errors
+                    .push((path.to_path_buf(), LoadError::Unreadable));
+                return;
+            };
+            let mut files = dir
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.is_file()
+                        && path
+                            .extension()
+                            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+                })
+                .collect::<Vec<_>>();
+            files.sort();
+            for file in files {
+                self.add_file(&file);
+            }
+        } else {
+            self.add_file(path);
+        }
+    }
+
+    fn add_file(&mut self, path: &Path) {
+        if self.entries.iter().any(|entry| entry.source == path)
+            || self.errors.iter().any(|(error, _)| error == path)
+        {
+            return;
+        }
+        match LoadedSummary::read(path) {
+            Ok(loaded) => self.entries.push(loaded),
+            Err(error) => self.errors.push((path.to_path_buf(), error)),
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.errors.clear();
+    }
+}
+
+#[cfg(test)]
+impl LoadedSummary {
+    /// Parses the content of a Meldebestätigung file used as test fixture
+    #[allow(clippy::unwrap_used)]
+    pub(crate) fn from_test_file(name: &str, content: &str) -> Self {
+        LoadedSummary {
+            source: PathBuf::from(name),
+            summary: SubmissionSummary::from_str(content).unwrap(),
+            content: content.as_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MELDEBESTAETIGUNG: &str = "Vorgangsnummer,Meldebestaetigung
+fb69c479d781e043a067bf757b9e0fa4f47a40cedd01c66b83eacfcca1fa6719,IBE+A800000001+A800000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+213b42fdf11586f0e2149cfca5084da982a042a37e889c16f5fc5c13c65a2519
+";
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_keep_parsed_content() {
+        let dir = std::env::temp_dir().join(format!("collection-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("meldebestaetigung.csv");
+        fs::write(&path, MELDEBESTAETIGUNG).unwrap();
+
+        let loaded = LoadedSummary::read(&path).unwrap();
+        fs::write(&path, "geändert").unwrap();
+
+        assert_eq!(loaded.content, MELDEBESTAETIGUNG.as_bytes());
+        assert_eq!(
+            LoadedSummary::read(&dir.join("fehlt.csv")).err(),
+            Some(LoadError::Unreadable)
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,58 @@
+use submission_summary::{Field, SubmissionSummary};
+
+/// Field of two Meldebestätigungen lined up side by side
+pub(crate) struct FieldComparison {
+    pub(crate) left: Field,
+    pub(crate) right: Field,
+}
+
+impl FieldComparison {
+    pub(crate) fn label(&self) -> &'static str {
+        self.left.label
+    }
+
+    pub(crate) fn differs(&self) -> bool {
+        self.left.raw != self.right.raw
+    }
+}
+
+pub(crate) fn compare(left: &SubmissionSummary, right: &SubmissionSummary) -> Vec<FieldComparison> {
+    left.fields()
+        .into_iter()
+        .zip(right.fields())
+        .map(|(left, right)| FieldComparison { left, right })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const ERSTMELDUNG: &str = "Vorgangsnummer,Meldebestaetigung\nbad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31";
+    const KORREKTUR: &str = "Vorgangsnummer,Meldebestaetigung\nbad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&3&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31";
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_not_find_differences() {
+        let left = SubmissionSummary::from_str(ERSTMELDUNG).unwrap();
+        let right = SubmissionSummary::from_str(ERSTMELDUNG).unwrap();
+
+        assert!(!compare(&left, &right).iter().any(FieldComparison::differs));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_find_differences() {
+        let left = SubmissionSummary::from_str(ERSTMELDUNG).unwrap();
+        let right = SubmissionSummary::from_str(KORREKTUR).unwrap();
+
+        let differences = compare(&left, &right)
+            .into_iter()
+            .filter(FieldComparison::differs)
+            .map(|comparison| comparison.label())
+            .collect::<Vec<_>>();
+
+        assert_eq!(differences, vec!["Typ der Meldung", "Hash-String"]);
+    }
+}
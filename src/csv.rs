@@ -0,0 +1,91 @@
+/// Joins values to a comma separated line, quoting values if required
+pub(crate) fn line<S: AsRef<str>>(values: &[S]) -> String {
+    values
+        .iter()
+        .map(|value| escape(value.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Splits a line into its values at the given delimiter, respecting quoted values
+pub(crate) fn split(line: &str, delimiter: char) -> Vec<String> {
+    let mut values = vec![];
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                value.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => values.push(std::mem::take(&mut value)),
+            c => value.push(c),
+        }
+    }
+    values.push(value);
+    values
+}
+
+/// True if the line consists of the columns of the given header, ignoring surrounding whitespace
+/// of each column and a leading byte order mark
+pub(crate) fn is_header(line: &str, header: &str, delimiter: char) -> bool {
+    line.trim_start_matches('\u{feff}')
+        .split(delimiter)
+        .map(str::trim)
+        .eq(header.split(delimiter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("GKV", "GKV")]
+    #[case(
+        "Gießen und Marburg, Standort Gießen",
+        "\"Gießen und Marburg, Standort Gießen\""
+    )]
+    #[case("Wert \"A\"", "\"Wert \"\"A\"\"\"")]
+    fn test_should_escape(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(escape(value), expected);
+    }
+
+    #[test]
+    fn test_should_join_line() {
+        assert_eq!(line(&["a", "b,c", ""]), "a,\"b,c\",");
+    }
+
+    #[rstest]
+    #[case("a,b,c", ',', &["a", "b", "c"])]
+    #[case("a;\"b;c\";", ';', &["a", "b;c", ""])]
+    #[case("\"Wert \"\"A\"\"\",b", ',', &["Wert \"A\"", "b"])]
+    #[case("a,b;c", ';', &["a,b", "c"])]
+    fn test_should_split_line(
+        #[case] line: &str,
+        #[case] delimiter: char,
+        #[case] expected: &[&str],
+    ) {
+        assert_eq!(split(line, delimiter), expected);
+    }
+
+    #[rstest]
+    #[case("TAN oder Code;Fallnummer", true)]
+    #[case("\u{feff}TAN oder Code ; Fallnummer ", true)]
+    #[case("TAN;Fallnummer", false)]
+    #[case("T1;MTB-1", false)]
+    #[case("TAN oder Code;Fallnummer;Bemerkung", false)]
+    fn test_should_check_header(#[case] line: &str, #[case] expected: bool) {
+        assert_eq!(is_header(line, "TAN oder Code;Fallnummer", ';'), expected);
+    }
+}
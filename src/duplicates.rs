@@ -0,0 +1,127 @@
+use crate::collection::LoadedSummary;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use submission_summary::RawValue;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum DuplicateKind {
+    Tan,
+    HashWert,
+    DateCounterIk,
+}
+
+impl Display for DuplicateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DuplicateKind::Tan => write!(f, "Doppelte TAN"),
+            DuplicateKind::HashWert => write!(f, "Doppelter Hash-Wert"),
+            DuplicateKind::DateCounterIk => {
+                write!(
+                    f,
+                    "Doppelte laufende Nummer für Datum und Leistungserbringer"
+                )
+            }
+        }
+    }
+}
+
+/// Meldebestätigungen sharing the same value for the given kind of duplicate
+pub(crate) struct Duplicate<'a> {
+    pub(crate) kind: DuplicateKind,
+    pub(crate) key: String,
+    pub(crate) entries: Vec<&'a LoadedSummary>,
+}
+
+pub(crate) fn duplicates(entries: &[LoadedSummary]) -> Vec<Duplicate<'_>> {
+    let mut grouped = BTreeMap::<(DuplicateKind, String), Vec<&LoadedSummary>>::new();
+    for loaded in entries {
+        let summary = &loaded.summary;
+        for key in [
+            (DuplicateKind::Tan, summary.tan().raw_value().to_string()),
+            (
+                DuplicateKind::HashWert,
+                summary.hash_wert().raw_value().to_string(),
+            ),
+            (
+                DuplicateKind::DateCounterIk,
+                format!(
+                    "{} / {} / {}",
+                    summary.date(),
+                    summary.counter(),
+                    summary.ik()
+                ),
+            ),
+        ] {
+            grouped.entry(key).or_default().push(loaded);
+        }
+    }
+
+    grouped
+        .into_iter()
+        .filter(|(_, entries)| entries.len() > 1)
+        .map(|((kind, key), entries)| Duplicate { kind, key, entries })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &str = "Vorgangsnummer,Meldebestaetigung
+a929f61b1da6932751bb72777cdd31759658049b4a711acabfbc9d7b73fc57d9,IBE+A600000001+A600000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+28e99e6ae9de91d6b1ccfd43b26f53021561cbc16a2402d58391bb5dd7978b4a
+";
+    const SECOND: &str = "Vorgangsnummer,Meldebestaetigung
+7b334604b24f5d7ec7a9cd0d9ddc0be16d31457249b24199628b18d78bbfad02,IBE+A600000002+A600000002&20240701002&260620431&KDKK00007&0&O&9&1&C&0&1+9+53b7fbb61b464c8fdb21109fa5296644239dd4aa1a146fa2d23388fd4cbcb41f
+";
+    const THIRD: &str = "Vorgangsnummer,Meldebestaetigung
+3a9cc8c7b35b7a89ed791b18962f5b370db9c3afcdc04a70a722f8cf4e25baff,IBE+A600000003+A600000003&20240701003&260620431&KDKK00007&0&O&9&1&C&0&1+9+96471449be371bc5cf88783db12fb4edaea60e10b962a677769fc02a81236589
+";
+    /// Same TAN, date and counter as [`FIRST`] for another case
+    const SAME_TAN_AND_COUNTER: &str = "Vorgangsnummer,Meldebestaetigung
+a929f61b1da6932751bb72777cdd31759658049b4a711acabfbc9d7b73fc57d9,IBE+A600000004+A600000004&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+e8c9468e53315a6f5c196f7880325746e79126660097bd4f421d87c4ab9185f9
+";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    #[test]
+    fn test_should_not_find_duplicates() {
+        let entries = vec![loaded(FIRST), loaded(SECOND)];
+
+        assert!(duplicates(&entries).is_empty());
+    }
+
+    #[test]
+    fn test_should_find_duplicate_tan_and_counter() {
+        let entries = vec![loaded(FIRST), loaded(SAME_TAN_AND_COUNTER), loaded(THIRD)];
+
+        let duplicates = duplicates(&entries);
+
+        assert_eq!(
+            duplicates
+                .iter()
+                .map(|duplicate| (duplicate.kind, duplicate.entries.len()))
+                .collect::<Vec<_>>(),
+            vec![(DuplicateKind::Tan, 2), (DuplicateKind::DateCounterIk, 2)]
+        );
+        assert_eq!(duplicates[0].key, entries[0].summary.tan().raw_value());
+    }
+
+    #[test]
+    fn test_should_find_duplicate_hash_of_same_file() {
+        let entries = vec![loaded(FIRST), loaded(FIRST)];
+
+        assert_eq!(
+            duplicates(&entries)
+                .iter()
+                .map(|duplicate| duplicate.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                DuplicateKind::Tan,
+                DuplicateKind::HashWert,
+                DuplicateKind::DateCounterIk
+            ]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::str::FromStr;
+use submission_summary::{CSV_HEADER, ParseError, SubmissionSummary};
+
+/// Field of the editor to be changed by user input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum EditorField {
+    Tan,
+    Code,
+    Date,
+    Counter,
+    Ik,
+    Datacenter,
+    TypDerMeldung,
+    Indikationsbereich,
+    UnknownHashStringValue,
+    Kostentraeger,
+    ArtDerDaten,
+    ArtDerSequenzierung,
+    UnknownSegment,
+}
+
+/// Raw values of a Meldebestätigung to be entered manually.
+///
+/// The hash value is not entered but always computed from the other values.
+pub(crate) struct Editor {
+    pub(crate) tan: String,
+    pub(crate) code: String,
+    pub(crate) date: String,
+    pub(crate) counter: String,
+    pub(crate) ik: String,
+    pub(crate) datacenter: String,
+    pub(crate) typ_der_meldung: String,
+    pub(crate) indikationsbereich: String,
+    /// Value of the hash string between Indikationsbereich and Kostenträger. Its meaning is not
+    /// documented and it is not evaluated, usually `9`.
+    pub(crate) unknown_hash_string_value: String,
+    pub(crate) kostentraeger: String,
+    pub(crate) art_der_daten: String,
+    pub(crate) art_der_sequenzierung: String,
+    pub(crate) accepted: bool,
+    /// Segment between hash string and hash value. Its meaning is not documented and it is not
+    /// evaluated, usually `9`.
+    pub(crate) unknown_segment: String,
+}
+
+impl Default for Editor {
+    fn default() -> Self {
+        Self {
+            tan: String::new(),
+            code: String::new(),
+            date: String::new(),
+            counter: "001".to_string(),
+            ik: String::new(),
+            datacenter: String::new(),
+            typ_der_meldung: "0".to_string(),
+            indikationsbereich: String::new(),
+            unknown_hash_string_value: "9".to_string(),
+            kostentraeger: String::new(),
+            art_der_daten: String::new(),
+            art_der_sequenzierung: "0".to_string(),
+            accepted: true,
+            unknown_segment: "9".to_string(),
+        }
+    }
+}
+
+impl Editor {
+    pub(crate) fn set(&mut self, field: EditorField, value: &str) {
+        let value = value.trim().to_string();
+        match field {
+            EditorField::Tan => self.tan = value,
+            EditorField::Code => self.code = value,
+            EditorField::Date => self.date = value,
+            EditorField::Counter => self.counter = value,
+            EditorField::Ik => self.ik = value,
+            EditorField::Datacenter => self.datacenter = value,
+            EditorField::TypDerMeldung => self.typ_der_meldung = value,
+            EditorField::Indikationsbereich => self.indikationsbereich = value,
+            EditorField::UnknownHashStringValue => self.unknown_hash_string_value = value,
+            EditorField::Kostentraeger => self.kostentraeger = value,
+            EditorField::ArtDerDaten => self.art_der_daten = value,
+            EditorField::ArtDerSequenzierung => self.art_der_sequenzierung = value,
+            EditorField::UnknownSegment => self.unknown_segment = value,
+        }
+    }
+
+    /// The '&' separated part of the Meldebestätigung the hash value is computed from
+    pub(crate) fn hash_string(&self) -> String {
+        [
+            self.code.as_str(),
+            &format!("{}{}", self.date.replace('-', ""), self.counter),
+            &self.ik,
+            &self.datacenter,
+            &self.typ_der_meldung,
+            &self.indikationsbereich,
+            &self.unknown_hash_string_value,
+            &self.kostentraeger,
+            &self.art_der_daten,
+            &self.art_der_sequenzierung,
+            if self.accepted { "1" } else { "0" },
+        ]
+        .join("&")
+    }
+
+    pub(crate) fn hash_wert(&self) -> String {
+        SubmissionSummary::hash(&self.hash_string())
+    }
+
+    /// The data line as it appears within the CSV file
+    pub(crate) fn line(&self) -> String {
+        format!(
+            "{},IBE+{}+{}+{}+{}",
+            self.tan,
+            self.code,
+            self.hash_string(),
+            self.unknown_segment,
+            self.hash_wert()
+        )
+    }
+
+    pub(crate) fn csv(&self) -> String {
+        format!("{CSV_HEADER}\n{}\n", self.line())
+    }
+
+    /// Parses the entered values the same way a loaded file is parsed
+    pub(crate) fn summary(&self) -> Result<SubmissionSummary, ParseError> {
+        SubmissionSummary::from_str(&self.csv())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use submission_summary::{Datacenter, Ik};
+
+    fn test_editor() -> Editor {
+        Editor {
+            tan: "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31".to_string(),
+            code: "A123456789".to_string(),
+            date: "2024-07-01".to_string(),
+            counter: "001".to_string(),
+            ik: "260530103".to_string(),
+            datacenter: "KDKK00001".to_string(),
+            typ_der_meldung: "0".to_string(),
+            indikationsbereich: "O".to_string(),
+            unknown_hash_string_value: "9".to_string(),
+            kostentraeger: "1".to_string(),
+            art_der_daten: "C".to_string(),
+            art_der_sequenzierung: "2".to_string(),
+            accepted: true,
+            unknown_segment: "9".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_should_create_csv() {
+        assert_eq!(
+            test_editor().csv(),
+            "Vorgangsnummer,Meldebestaetigung\nbad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31\n"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_update_hash_on_change() {
+        let mut editor = test_editor();
+        editor.set(EditorField::Datacenter, "KDKK00007");
+
+        let summary = editor.summary().unwrap();
+        assert_eq!(summary.datacenter(), &Datacenter::KDKK00007);
+        assert_eq!(summary.ik(), &Ik::Ik260530103);
+        assert!(summary.valid_hash());
+        assert_ne!(
+            editor.hash_wert(),
+            "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31"
+        );
+    }
+
+    #[test]
+    fn test_should_not_parse_incomplete_input() {
+        assert!(Editor::default().summary().is_err());
+    }
+
+    #[test]
+    fn test_should_report_parse_error() {
+        let mut editor = test_editor();
+        editor.set(EditorField::Date, "2024-13-01");
+
+        assert_eq!(editor.summary(), Err(ParseError::InvalidDate));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::collection::LoadedSummary;
+use serde_json::{Value, json};
+use std::fmt::Display;
+use submission_summary::{RawValue, SubmissionSummary};
+
+/// Official naming system of the Institutionskennzeichen
+const IK_SYSTEM: &str = "http://fhir.de/sid/arge-ik/iknr";
+/// Base of naming and code systems defined by this application
+const SYSTEM_BASE: &str = "https://github.com/diz-unimr/submission-summary-viewer/fhir";
+
+fn coding(system: &str, value: &(impl RawValue + Display)) -> Value {
+    json!({
+        "system": format!("{SYSTEM_BASE}/CodeSystem/{system}"),
+        "code": value.raw_value(),
+        "display": value.to_string(),
+    })
+}
+
+fn coded_input(text: &str, system: &str, value: &(impl RawValue + Display)) -> Value {
+    json!({
+        "type": { "text": text },
+        "valueCoding": coding(system, value),
+    })
+}
+
+fn string_input(text: &str, value: &str) -> Value {
+    json!({
+        "type": { "text": text },
+        "valueString": value,
+    })
+}
+
+fn output(text: &str, value: bool) -> Value {
+    json!({
+        "type": { "text": text },
+        "valueBoolean": value,
+    })
+}
+
+fn identifier(system: &str, value: &str) -> Value {
+    json!({
+        "system": format!("{SYSTEM_BASE}/NamingSystem/{system}"),
+        "value": value,
+    })
+}
+
+/// FHIR R4 Task documenting the confirmed submission. TAN and code are given as identifiers,
+/// the Leistungserbringer as logical reference by IK. If redacted, identifiers and hash are
+/// omitted, since masked values must not be mistaken for real ones.
+pub(crate) fn task(summary: &SubmissionSummary, redacted: bool) -> Value {
+    let mut task = json!({
+        "resourceType": "Task",
+        "status": "completed",
+        "intent": "order",
+        "code": {
+            "coding": [coding("typ-der-meldung", summary.typ_der_meldung())],
+            "text": "Meldebestätigung nach §64e SGB V",
+        },
+        "owner": {
+            "identifier": {
+                "system": IK_SYSTEM,
+                "value": summary.ik().raw_value(),
+            },
+            "display": summary.ik().to_string(),
+        },
+        "input": [
+            string_input("Laufende Nummer", summary.counter().raw_value()),
+            coded_input("Datenknoten", "datenknoten", summary.datacenter()),
+            coded_input("Indikationsbereich", "indikationsbereich", summary.indikationsbereich()),
+            coded_input("Kostenträger", "kostentraeger", summary.kostentraeger()),
+            coded_input("Art der Daten", "art-der-daten", summary.art_der_daten()),
+            coded_input(
+                "Art der Sequenzierung",
+                "art-der-sequenzierung",
+                summary.art_der_sequenzierung(),
+            ),
+        ],
+        "output": [
+            output("Qualitätskontrolle bestanden", summary.accepted()),
+            output("Hash-Wert gültig", summary.valid_hash()),
+            output("Meldebestätigung gültig", summary.is_valid()),
+        ],
+    });
+    if !redacted {
+        task["identifier"] = json!([
+            identifier("tan", summary.tan().raw_value()),
+            identifier("code", summary.code().raw_value()),
+        ]);
+        if let Some(input) = task["input"].as_array_mut() {
+            input.push(string_input("Sha256-Hash", summary.hash_wert().raw_value()));
+        }
+    }
+    if let Some(date) = summary.parsed_date() {
+        task["authoredOn"] = json!(date.format("%Y-%m-%d").to_string());
+    }
+    task
+}
+
+/// FHIR R4 Bundle of type collection containing a Task for each Meldebestätigung
+pub(crate) fn bundle(entries: &[LoadedSummary], redacted: bool) -> Value {
+    json!({
+        "resourceType": "Bundle",
+        "type": "collection",
+        "entry": entries
+            .iter()
+            .map(|loaded| json!({ "resource": task(&loaded.summary, redacted) }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &str = "Vorgangsnummer,Meldebestaetigung
+2f8fb9ab480ecc9bb2e25a6f6faeec45f140db40cb5c9eccb1d00ead2f1e18f1,IBE+A123456789+A123456789&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+b437839d3b05694d116435e069232161670da54f83b4448e10305b63d5748717
+";
+    const SECOND: &str = "Vorgangsnummer,Meldebestaetigung
+876aa3aeb002c1b60d0ae678db2560e216155992ea716a210ff638294a537437,IBE+A140000002+A140000002&20240701002&260620431&KDKK00007&0&O&9&1&C&0&1+9+888252116036a8e127434963ac3761ee5d222164eee6ef080dda29c10664879e
+";
+    const FIRST_TAN: &str = "2f8fb9ab480ecc9bb2e25a6f6faeec45f140db40cb5c9eccb1d00ead2f1e18f1";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    #[test]
+    fn test_should_map_to_task() {
+        let task = task(&loaded(FIRST).summary, false);
+
+        assert_eq!(task["resourceType"], "Task");
+        assert_eq!(task["identifier"][0]["value"], FIRST_TAN);
+        assert_eq!(task["identifier"][1]["value"], "A123456789");
+        assert_eq!(task["owner"]["identifier"]["system"], IK_SYSTEM);
+        assert_eq!(task["owner"]["identifier"]["value"], "260620431");
+        assert_eq!(task["authoredOn"], "2024-07-01");
+        assert_eq!(task["input"][3]["valueCoding"]["code"], "1");
+        assert_eq!(task["input"][3]["valueCoding"]["display"], "GKV");
+        assert_eq!(
+            task["input"][6]["valueString"],
+            "b437839d3b05694d116435e069232161670da54f83b4448e10305b63d5748717"
+        );
+        assert_eq!(task["output"][1]["valueBoolean"], true);
+    }
+
+    #[test]
+    fn test_should_redact_identifiers() {
+        let task = task(&loaded(FIRST).summary, true);
+
+        assert!(task.get("identifier").is_none());
+        assert!(!task.to_string().contains(FIRST_TAN));
+        assert!(!task.to_string().contains("A123456789"));
+        assert!(!task.to_string().contains("Sha256-Hash"));
+    }
+
+    #[test]
+    fn test_should_create_bundle() {
+        let bundle = bundle(&[loaded(FIRST), loaded(SECOND)], false);
+
+        assert_eq!(bundle["type"], "collection");
+        assert_eq!(bundle["entry"].as_array().map(Vec::len), Some(2));
+        assert_eq!(
+            bundle["entry"][1]["resource"]["identifier"][1]["value"],
+            "A140000002"
+        );
+    }
+}
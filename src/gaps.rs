@@ -0,0 +1,135 @@
+use crate::collection::LoadedSummary;
+use std::collections::BTreeMap;
+use submission_summary::{Ik, RawValue};
+
+/// Laufende Nummern of all Meldebestätigungen of a Leistungserbringer on a single date
+pub(crate) struct CounterSequence<'a> {
+    pub(crate) ik: &'a Ik,
+    pub(crate) date: &'a str,
+    pub(crate) counters: Vec<u32>,
+    pub(crate) missing: Vec<u32>,
+    pub(crate) repeated: Vec<u32>,
+}
+
+impl CounterSequence<'_> {
+    pub(crate) fn has_findings(&self) -> bool {
+        !self.missing.is_empty() || !self.repeated.is_empty()
+    }
+}
+
+/// Formats counters the same way they appear within a Meldebestätigung
+pub(crate) fn format_counters(counters: &[u32]) -> String {
+    counters
+        .iter()
+        .map(|counter| format!("{counter:03}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Analyses laufende Nummern per IK and date.
+///
+/// A sequence is expected to start at 1 without gaps up to the highest counter.
+pub(crate) fn counter_sequences(entries: &[LoadedSummary]) -> Vec<CounterSequence<'_>> {
+    let mut grouped = BTreeMap::<(&str, &str), (&Ik, Vec<u32>)>::new();
+    for loaded in entries {
+        let summary = &loaded.summary;
+        let Some(counter) = summary.parsed_counter() else {
+            continue;
+        };
+        grouped
+            .entry((summary.ik().raw_value(), summary.date().raw_value()))
+            .or_insert_with(|| (summary.ik(), vec![]))
+            .1
+            .push(counter);
+    }
+
+    grouped
+        .into_iter()
+        .map(|((_, date), (ik, mut counters))| {
+            counters.sort_unstable();
+
+            let max = counters.last().copied().unwrap_or_default();
+            let missing = (1..max)
+                .filter(|counter| counters.binary_search(counter).is_err())
+                .collect();
+
+            let mut repeated = counters
+                .windows(2)
+                .filter(|pair| pair[0] == pair[1])
+                .map(|pair| pair[0])
+                .collect::<Vec<_>>();
+            repeated.dedup();
+
+            CounterSequence {
+                ik,
+                date,
+                counters,
+                missing,
+                repeated,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARBURG_1: &str = "Vorgangsnummer,Meldebestaetigung
+d731feb9bbf88fa3d0edf7fa40a0d6c8ccc980fdee81e32af6715f6129f2635f,IBE+A700000001+A700000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+b3830b97bde2f274e0619145de36816bea0561ea657cef004b1bf0a26030abe9
+";
+    const MARBURG_2: &str = "Vorgangsnummer,Meldebestaetigung
+ba33fb00610986914509740cbb699ab846c471edcd61c7bc30eb9247f5be2e27,IBE+A700000002+A700000002&20240701002&260620431&KDKK00007&0&O&9&1&C&0&1+9+f7287542825bf31340022f5537f6ea5e60b12f5e106e2fe2d8eef0258eb88488
+";
+    const MARBURG_4: &str = "Vorgangsnummer,Meldebestaetigung
+2daa2363c9fde046148419b7ffb8f992383a004bcae77cc94dcede20744cf27c,IBE+A700000004+A700000004&20240701004&260620431&KDKK00007&0&O&9&1&C&0&1+9+7d6c670da570f1e84f3e8fa5acec8a73d698921bd2410d2d83fe01ceca264e77
+";
+    const MARBURG_NEXT_DAY: &str = "Vorgangsnummer,Meldebestaetigung
+3d833c7710128823464bfc36c369f55697457698795d593f0e0e415a7d1b7092,IBE+A700000005+A700000005&20240702001&260620431&KDKK00007&0&O&9&1&C&0&1+9+633d171b6e0585a11f7ca88b11b734cea24d7f0da5b9f81f7a0e39a2d3d9e98d
+";
+    const GIESSEN_2: &str = "Vorgangsnummer,Meldebestaetigung
+19aad8ec64b843a6ea461bf371a85c368edf67f2ff5e9f5b16fc4d784d20f6d1,IBE+A700000006+A700000006&20240701002&260610279&KDKK00007&0&O&9&1&C&0&1+9+5b05f218560afe75523f08cf671d1c8568ec9fbc4ad85649b231859805f241b0
+";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    #[test]
+    fn test_should_not_find_gaps() {
+        let entries = vec![
+            loaded(MARBURG_2),
+            loaded(MARBURG_1),
+            loaded(MARBURG_NEXT_DAY),
+        ];
+
+        let sequences = counter_sequences(&entries);
+
+        assert_eq!(sequences.len(), 2);
+        assert_eq!(sequences[0].counters, vec![1, 2]);
+        assert!(!sequences.iter().any(CounterSequence::has_findings));
+    }
+
+    #[test]
+    fn test_should_find_missing_and_repeated_counters() {
+        let entries = vec![
+            loaded(MARBURG_1),
+            loaded(MARBURG_4),
+            loaded(MARBURG_4),
+            loaded(GIESSEN_2),
+        ];
+
+        let sequences = counter_sequences(&entries);
+
+        assert_eq!(sequences.len(), 2);
+        assert_eq!(sequences[0].ik, &Ik::Ik260610279);
+        assert_eq!(sequences[0].missing, vec![1]);
+        assert_eq!(sequences[1].missing, vec![2, 3]);
+        assert_eq!(sequences[1].repeated, vec![4]);
+    }
+
+    #[test]
+    fn test_should_format_counters() {
+        assert_eq!(format_counters(&[1, 2, 123]), "001, 002, 123");
+    }
+}
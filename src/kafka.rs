@@ -0,0 +1,251 @@
+use crate::redaction;
+use rdkafka::consumer::{BaseConsumer, Consumer};
+use rdkafka::error::KafkaError;
+use rdkafka::message::{DeliveryResult, Message};
+use rdkafka::producer::{BaseProducer, BaseRecord, Producer, ProducerContext};
+use rdkafka::{ClientConfig, ClientContext};
+use std::sync::Mutex;
+use std::time::Duration;
+use submission_summary::json::ParsedSummary;
+use submission_summary::{RawValue, SubmissionSummary};
+
+const POLL_TIMEOUT: Duration = Duration::from_secs(1);
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Brokers and topics of the headless Kafka mode
+pub(crate) struct KafkaConfig {
+    pub(crate) bootstrap_servers: String,
+    pub(crate) group_id: String,
+    pub(crate) input_topic: String,
+    pub(crate) output_topic: String,
+    pub(crate) dead_letter_topic: String,
+}
+
+/// Keeps the error of a failed delivery, since flushing only waits for the delivery reports
+#[derive(Default)]
+struct DeliveryContext {
+    failure: Mutex<Option<KafkaError>>,
+}
+
+impl DeliveryContext {
+    fn take_failure(&self) -> Option<KafkaError> {
+        self.failure
+            .lock()
+            .ok()
+            .and_then(|mut failure| failure.take())
+    }
+}
+
+impl ClientContext for DeliveryContext {}
+
+impl ProducerContext for DeliveryContext {
+    type DeliveryOpaque = ();
+
+    fn delivery(&self, delivery_result: &DeliveryResult<'_>, (): Self::DeliveryOpaque) {
+        if let (Err((error, _)), Ok(mut failure)) = (delivery_result, self.failure.lock()) {
+            *failure = Some(error.clone());
+        }
+    }
+}
+
+/// Result of processing a consumed message with the JSON to be published
+#[derive(Debug, PartialEq)]
+pub(crate) enum Outcome {
+    Processed { tan: String, json: String },
+    DeadLetter { json: String },
+}
+
+/// Parses a raw Meldebestätigung line. The CSV header is optional. Every message results in
+/// an outcome to be published, so no message is skipped.
+pub(crate) fn process(payload: &[u8]) -> Outcome {
+    // Serializing a JSON value cannot fail, unlike serializing arbitrary types
+    let dead_letter = |error: &str| Outcome::DeadLetter {
+        json: serde_json::json!({
+            "input": String::from_utf8_lossy(payload),
+            "error": error,
+        })
+        .to_string(),
+    };
+
+    let Ok(input) = std::str::from_utf8(payload) else {
+        return dead_letter("Nachricht ist kein gültiges UTF-8");
+    };
+    match SubmissionSummary::from_csv_or_line(input) {
+        Ok(summary) => match serde_json::to_string(&ParsedSummary::from(&summary)) {
+            Ok(json) => Outcome::Processed {
+                tan: summary.tan().raw_value().to_string(),
+                json,
+            },
+            Err(_) => dead_letter("Ergebnis konnte nicht erstellt werden"),
+        },
+        Err(error) => dead_letter(&error.to_string()),
+    }
+}
+
+/// Consumes the input topic until a fatal error occurs or a result cannot be delivered. Results are
+/// keyed by TAN, dead letters keep the key of the consumed message. Offsets are committed after
+/// the broker has confirmed the delivery of the result.
+pub(crate) fn run(config: &KafkaConfig) -> Result<(), KafkaError> {
+    let consumer: BaseConsumer = ClientConfig::new()
+        .set("bootstrap.servers", &config.bootstrap_servers)
+        .set("group.id", &config.group_id)
+        .set("auto.offset.reset", "earliest")
+        .set("enable.auto.offset.store", "false")
+        .create()?;
+    let producer: BaseProducer<DeliveryContext> = ClientConfig::new()
+        .set("bootstrap.servers", &config.bootstrap_servers)
+        .create_with_context(DeliveryContext::default())?;
+    consumer.subscribe(&[&config.input_topic])?;
+
+    loop {
+        let Some(message) = consumer.poll(POLL_TIMEOUT) else {
+            continue;
+        };
+        let message = match message {
+            Ok(message) => message,
+            Err(error) if consumer.client().fatal_error().is_some() => return Err(error),
+            Err(error) => {
+                eprintln!("Fehler beim Lesen: {error}");
+                continue;
+            }
+        };
+        let key = message.key().unwrap_or_default();
+        match process(message.payload().unwrap_or_default()) {
+            Outcome::Processed { tan, json } => {
+                println!(
+                    "Verarbeitet: Offset {}, TAN {}",
+                    message.offset(),
+                    redaction::redact(&tan)
+                );
+                producer
+                    .send(
+                        BaseRecord::to(&config.output_topic)
+                            .key(&tan)
+                            .payload(&json),
+                    )
+                    .map_err(|(error, _)| error)?;
+            }
+            Outcome::DeadLetter { json } => {
+                eprintln!("Nicht lesbar: Offset {}", message.offset());
+                producer
+                    .send(
+                        BaseRecord::to(&config.dead_letter_topic)
+                            .key(key)
+                            .payload(&json),
+                    )
+                    .map_err(|(error, _)| error)?;
+            }
+        }
+        producer.flush(FLUSH_TIMEOUT)?;
+        if let Some(error) = producer.context().take_failure() {
+            return Err(error);
+        }
+        consumer.store_offset_from_message(&message)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+    use submission_summary::CSV_HEADER;
+
+    const LINE: &str = "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31";
+
+    /// TAN of processed messages and the published JSON
+    #[allow(clippy::unwrap_used)]
+    fn published(payload: &[u8]) -> (Option<String>, serde_json::Value) {
+        let (tan, json) = match process(payload) {
+            Outcome::Processed { tan, json } => (Some(tan), json),
+            Outcome::DeadLetter { json } => (None, json),
+        };
+        (tan, serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn test_should_process_line_with_and_without_header() {
+        let (tan, value) = published(LINE.as_bytes());
+
+        assert_eq!(
+            tan.as_deref(),
+            Some("bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31")
+        );
+        assert_eq!(value["summary"]["code"], "A123456789");
+        assert_eq!(value["validation"]["validHash"], true);
+        assert_eq!(
+            published(format!("{CSV_HEADER}\n{LINE}\n").as_bytes()),
+            (tan, value)
+        );
+    }
+
+    #[test]
+    fn test_should_create_dead_letter() {
+        let (tan, value) = published(b"kaputt");
+
+        assert_eq!(tan, None);
+        assert_eq!(value["input"], "kaputt");
+        assert_eq!(
+            value["error"],
+            "Zeile besteht nicht aus Vorgangsnummer und Meldebestätigung"
+        );
+        assert_eq!(published(b"\xff").0, None);
+        assert_eq!(
+            published(b"\xff").1["error"],
+            "Nachricht ist kein gültiges UTF-8"
+        );
+    }
+
+    /// Requires a broker, e.g. started by `docker compose -f kafka-compose.yml up`
+    #[test]
+    #[ignore = "requires a Kafka broker"]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_publish_results_and_dead_letters() {
+        let bootstrap_servers = std::env::var("KAFKA_BOOTSTRAP_SERVERS")
+            .unwrap_or_else(|_| "localhost:9092".to_string());
+        let suffix = std::process::id();
+        let config = KafkaConfig {
+            bootstrap_servers: bootstrap_servers.clone(),
+            group_id: format!("test-{suffix}"),
+            input_topic: format!("test-input-{suffix}"),
+            output_topic: format!("test-output-{suffix}"),
+            dead_letter_topic: format!("test-dlq-{suffix}"),
+        };
+
+        let producer: BaseProducer = ClientConfig::new()
+            .set("bootstrap.servers", &bootstrap_servers)
+            .create()
+            .unwrap();
+        for payload in [LINE, "kaputt"] {
+            producer
+                .send(BaseRecord::<(), _>::to(&config.input_topic).payload(payload))
+                .unwrap();
+        }
+        producer.flush(FLUSH_TIMEOUT).unwrap();
+
+        let topics = [
+            config.output_topic.clone(),
+            config.dead_letter_topic.clone(),
+        ];
+        std::thread::spawn(move || run(&config));
+
+        let consumer: BaseConsumer = ClientConfig::new()
+            .set("bootstrap.servers", &bootstrap_servers)
+            .set("group.id", format!("test-reader-{suffix}"))
+            .set("auto.offset.reset", "earliest")
+            .create()
+            .unwrap();
+        consumer
+            .subscribe(&topics.iter().map(String::as_str).collect::<Vec<_>>())
+            .unwrap();
+        let mut received = vec![];
+        let started = Instant::now();
+        while received.len() < 2 && started.elapsed() < Duration::from_mins(1) {
+            if let Some(Ok(message)) = consumer.poll(POLL_TIMEOUT) {
+                received.push(message.topic().to_string());
+            }
+        }
+        received.sort();
+
+        assert_eq!(received, vec![topics[1].clone(), topics[0].clone()]);
+    }
+}
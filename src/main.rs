@@ -1,49 +1,132 @@
 #![windows_subsystem = "windows"]
 
-mod submission_summary;
+mod aggregate;
+mod archive;
+mod audit;
+mod billing;
+mod case_mapping;
+mod chains;
+mod cli;
+mod collection;
+mod compare;
+mod csv;
+mod duplicates;
+mod editor;
+mod fhir;
+mod gaps;
+#[cfg(feature = "kafka")]
+mod kafka;
+mod metadata;
+mod package;
+mod qc;
+mod reconciliation;
+mod redaction;
+mod report;
+mod review;
+mod server;
+mod statistics;
+mod table;
+mod tariff;
 
-use crate::submission_summary::{
-    ArtDerSequenzierung, CheckedValue, StringValue, SubmissionSummary,
-};
+use crate::aggregate::Aggregate;
+use crate::archive::{Archive, ArchivedSummary, ImportResult};
+use crate::audit::{AuditAction, AuditLog};
+use crate::billing::BillingItem;
+use crate::case_mapping::CaseMapping;
+use crate::chains::Chain;
+use crate::cli::Cli;
+use crate::collection::{Collection, LoadError, LoadedSummary};
+use crate::compare::FieldComparison;
+use crate::duplicates::{Duplicate, DuplicateKind};
+use crate::editor::{Editor, EditorField};
+use crate::metadata::GrzMetadata;
+use crate::qc::{QcFailure, QcState};
+use crate::reconciliation::{Reconciliation, Submission};
+use crate::review::{Review, ReviewStatus};
+use crate::statistics::{Dimension, MonthlyShare};
+use crate::table::SummaryTable;
+use crate::tariff::{Amount, PeriodTotal, TariffTable};
+use chrono::Local;
+use clap::Parser;
 use iced::border::Radius;
 use iced::font::Weight;
-use iced::widget::{button, column, container, row, rule, text, text_input, Row};
+use iced::widget::{
+    Column, Row, Space, button, checkbox, column, container, pick_list, row, rule, scrollable,
+    text, text_input,
+};
 use iced::window::Event;
 use iced::{
-    alignment, application, color, window, Background, Border, Color, Element, Font, Pixels, Task,
+    Background, Border, Color, Element, Font, Pixels, Task, alignment, application, color, window,
 };
 use iced::{Length, Settings};
 use std::cmp::PartialEq;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::slice;
 use std::str::FromStr;
+use submission_summary::{
+    ArtDerDaten, ArtDerSequenzierung, CheckedValue, Datacenter, Field, Ik, Indikationsbereich,
+    Kostentraeger, ParseError, RawValue, StringValue, SubmissionSummary, TypDerMeldung,
+};
 
 #[cfg(target_os = "linux")]
 use iced::window::settings::PlatformSpecific;
 
-fn main() -> iced::Result {
-    application(Ui::new, Ui::update, Ui::view)
-        .title("Submission Summary Viewer")
-        .settings(Settings {
-            default_text_size: Pixels::from(13),
-            ..Settings::default()
-        })
-        .window(window::Settings {
-            #[cfg(target_os = "windows")]
-            icon: window::icon::from_file_data(include_bytes!("../resources/icon.ico"), None).ok(),
-            #[cfg(target_os = "linux")]
-            icon: window::icon::from_file_data(include_bytes!("../resources/icon.png"), None).ok(),
-            #[cfg(target_os = "linux")]
-            platform_specific: PlatformSpecific {
-                application_id: "submission-summary-viewer".to_string(),
-                ..PlatformSpecific::default()
-            },
-            ..window::Settings::default()
-        })
-        .resizable(false)
-        .window_size((800, 600))
-        .subscription(Ui::subscription)
-        .run()
+fn main() -> ExitCode {
+    #[cfg(target_os = "windows")]
+    if std::env::args_os().len() > 1 {
+        attach_console();
+    }
+    let cli = Cli::parse();
+    if cli.command.is_some() {
+        return cli.run();
+    }
+    match gui(cli.archive, cli.audit_log) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(_) => ExitCode::FAILURE,
+    }
+}
+
+/// Attaches to the console of the calling process, since the Windows subsystem does not provide
+/// one and output of commands would be lost otherwise
+#[cfg(target_os = "windows")]
+fn attach_console() {
+    use windows_sys::Win32::System::Console::{ATTACH_PARENT_PROCESS, AttachConsole};
+
+    // SAFETY: AttachConsole has no preconditions and fails if the parent has no console
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+fn gui(archive: Option<PathBuf>, audit_log: Option<PathBuf>) -> iced::Result {
+    application(
+        move || Ui::new(archive.clone(), audit_log.clone()),
+        Ui::update,
+        Ui::view,
+    )
+    .title("Submission Summary Viewer")
+    .settings(Settings {
+        default_text_size: Pixels::from(13),
+        ..Settings::default()
+    })
+    .window(window::Settings {
+        #[cfg(target_os = "windows")]
+        icon: window::icon::from_file_data(include_bytes!("../resources/icon.ico"), None).ok(),
+        #[cfg(target_os = "linux")]
+        icon: window::icon::from_file_data(include_bytes!("../resources/icon.png"), None).ok(),
+        #[cfg(target_os = "linux")]
+        platform_specific: PlatformSpecific {
+            application_id: "submission-summary-viewer".to_string(),
+            ..PlatformSpecific::default()
+        },
+        ..window::Settings::default()
+    })
+    .resizable(false)
+    .window_size((800, 600))
+    .subscription(Ui::subscription)
+    .run()
 }
 
 #[derive(Debug, Clone)]
@@ -51,84 +134,648 @@ enum Message {
     PickFile,
     ClearFile,
     ReadFile(Result<PathBuf, ()>),
+    PickCompareFile(usize),
+    ClearCompareFile(usize),
+    ReadCompareFile(usize, Result<PathBuf, ()>),
+    SelectMode(Mode),
+    SelectAnalysis(Analysis),
+    AddFiles,
+    AddFolder,
+    FilesAdded(Vec<PathBuf>),
+    ClearCollection,
+    PickCaseMapping,
+    ReadCaseMapping(Result<PathBuf, ()>),
+    ClearCaseMapping,
+    Passphrase(String),
+    UnlockCaseMapping,
+    LockCaseMapping,
+    ExportCaseNumbers(bool),
+    Redact(bool),
+    IncludeArchive(bool),
+    ArchiveQuery(String),
+    ShowArchived(usize),
+    FilterArchive(StatusFilter),
+    ReviewStatus(ReviewStatus),
+    ReviewReviewer(String),
+    ReviewNotes(String),
+    SaveReview,
+    FollowUpDays(String),
+    OnlyFindings(bool),
+    QcOverdueDays(String),
+    SelectDimension(Dimension),
+    AggregateThreshold(String),
+    PickSubmissionLog,
+    ReadSubmissionLog(Result<PathBuf, ()>),
+    PickTariffTable,
+    ReadTariffTable(Result<PathBuf, ()>),
+    AddMetadataFiles,
+    MetadataFilesAdded(Vec<PathBuf>),
+    ClearMetadata,
+    Export(Export),
+    Exported(Result<PathBuf, ()>),
+    EditorInput(EditorField, String),
+    EditorAccepted(bool),
+    ExportEditor,
+    EditorExported(Result<PathBuf, ()>),
     Empty,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Viewer,
+    Editor,
+    Compare,
+    Collection,
+    Archive,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Analysis {
+    Chains,
+    Duplicates,
+    Gaps,
+    QcFailures,
+    Reconciliation,
+    Metadata,
+    Billing,
+    Statistics,
+}
+
+/// Export of the loaded collection
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Export {
+    BillingCsv,
+    AggregateCsv,
+    AggregateJson,
+    SummaryPdf,
+    SummaryFhir,
+    FhirBundle,
+    TableCsv,
+    TableXlsx,
+}
+
+/// Filter of archived Meldebestätigungen by review status
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StatusFilter {
+    All,
+    Status(ReviewStatus),
+}
+
+impl StatusFilter {
+    fn values() -> Vec<Self> {
+        std::iter::once(StatusFilter::All)
+            .chain(ReviewStatus::values().into_iter().map(StatusFilter::Status))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for StatusFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatusFilter::All => write!(f, "Alle Status"),
+            StatusFilter::Status(status) => write!(f, "{status}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 enum Status {
     NoFile,
     FileLoaded,
-    ParseError,
+    ParseError(LoadError),
 }
 
+#[allow(clippy::struct_excessive_bools)]
 struct Ui {
+    mode: Mode,
     file_path: Option<PathBuf>,
     status: Status,
     submission_summary: Option<SubmissionSummary>,
+    /// Bytes of the file shown in the viewer as they have been parsed
+    file_content: Option<Vec<u8>>,
+    editor: Editor,
+    editor_export: Option<Result<PathBuf, ()>>,
+    compare: [Option<(PathBuf, Result<SubmissionSummary, LoadError>)>; 2],
+    collection: Collection,
+    archive: Result<Archive, ()>,
+    audit_log: Option<AuditLog>,
+    /// Warnings about archived TANs and audit log failures of the last action
+    warnings: Vec<String>,
+    archive_query: String,
+    archive_results: Vec<ArchivedSummary>,
+    archive_filter: StatusFilter,
+    /// Review of the Meldebestätigung shown in the viewer
+    review: Option<Review>,
+    review_saved: Option<Result<(), ()>>,
+    include_archive: bool,
+    /// Loaded and, if included, archived Meldebestätigungen to be analysed
+    entries: Vec<LoadedSummary>,
+    analysis: Analysis,
+    follow_up_days: String,
+    qc_overdue_days: String,
+    dimension: Dimension,
+    aggregate_threshold: String,
+    only_findings: bool,
+    submission_log: Option<(PathBuf, Result<Vec<Submission>, ()>)>,
+    metadata: Vec<(PathBuf, Result<GrzMetadata, ()>)>,
+    tariff_table: Option<(PathBuf, Result<TariffTable, ()>)>,
+    case_mapping_path: Option<PathBuf>,
+    passphrase: String,
+    /// Unlocked case mapping, none while locked
+    case_mapping: Option<Result<CaseMapping, ()>>,
+    /// Case numbers are only exported if explicitly requested
+    export_case_numbers: bool,
+    /// Masks TAN, code and hashes in the GUI and exports
+    redacted: bool,
+    export_status: Option<Result<PathBuf, ()>>,
 }
 
 impl Ui {
-    fn new() -> Self {
+    /// Uses the given archive and audit log files instead of the default locations
+    fn new(archive: Option<PathBuf>, audit_log: Option<PathBuf>) -> Self {
         Self {
+            mode: Mode::Viewer,
             file_path: None,
             status: Status::NoFile,
             submission_summary: None,
+            file_content: None,
+            editor: Editor::default(),
+            editor_export: None,
+            compare: [None, None],
+            collection: Collection::default(),
+            archive: archive
+                .or_else(Archive::default_path)
+                .ok_or(())
+                .and_then(|path| Archive::open(&path)),
+            audit_log: audit_log
+                .or_else(AuditLog::default_path)
+                .map(|path| AuditLog::new(&path)),
+            warnings: vec![],
+            archive_query: String::new(),
+            archive_results: vec![],
+            archive_filter: StatusFilter::All,
+            review: None,
+            review_saved: None,
+            include_archive: false,
+            entries: vec![],
+            analysis: Analysis::Chains,
+            follow_up_days: "365".to_string(),
+            qc_overdue_days: "30".to_string(),
+            dimension: Dimension::Indikationsbereich,
+            aggregate_threshold: aggregate::MIN_THRESHOLD.to_string(),
+            only_findings: false,
+            submission_log: None,
+            metadata: vec![],
+            tariff_table: None,
+            case_mapping_path: None,
+            passphrase: String::new(),
+            case_mapping: None,
+            export_case_numbers: false,
+            redacted: false,
+            export_status: None,
         }
     }
 
+    #[allow(clippy::too_many_lines)]
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
+            Message::ReadFile(file) if self.mode == Mode::Compare => {
+                let side = self.compare.iter().position(Option::is_none).unwrap_or(1);
+                self.update(Message::ReadCompareFile(side, file))
+            }
+            Message::ReadFile(file) if self.mode == Mode::Collection => {
+                self.update(Message::FilesAdded(file.into_iter().collect()))
+            }
             Message::ReadFile(file) => {
                 if let Ok(path) = file {
-                    self.file_path = Some(path);
-                    if let Ok(summary) = self.parse_file() {
-                        self.submission_summary = Some(summary);
-                        self.status = Status::FileLoaded;
-                    } else {
-                        self.submission_summary = None;
-                        self.status = Status::ParseError;
+                    self.mode = Mode::Viewer;
+                    self.file_path = Some(path.clone());
+                    let loaded = LoadedSummary::read(&path);
+                    self.warnings = vec![];
+                    self.audit(|log| {
+                        log.loaded(&path, loaded.as_ref().ok().map(|loaded| &loaded.summary))
+                    });
+                    match loaded {
+                        Ok(loaded) => {
+                            self.warnings.extend(self.import(slice::from_ref(&loaded)));
+                            self.review = self.load_review(&loaded.summary);
+                            self.submission_summary = Some(loaded.summary);
+                            self.file_content = Some(loaded.content);
+                            self.status = Status::FileLoaded;
+                        }
+                        Err(error) => {
+                            self.review = None;
+                            self.submission_summary = None;
+                            self.file_content = None;
+                            self.status = Status::ParseError(error);
+                        }
                     }
+                    self.review_saved = None;
                 }
                 Task::none()
             }
             Message::ClearFile => {
+                self.review = None;
                 self.file_path = None;
                 self.status = Status::NoFile;
                 self.submission_summary = None;
+                self.file_content = None;
                 Task::none()
             }
             Message::PickFile => Task::perform(Self::pick_file(), Message::ReadFile),
+            Message::PickCompareFile(side) => Task::perform(Self::pick_file(), move |file| {
+                Message::ReadCompareFile(side, file)
+            }),
+            Message::ClearCompareFile(side) => {
+                self.compare[side] = None;
+                Task::none()
+            }
+            Message::ReadCompareFile(side, file) => {
+                if let Ok(path) = file {
+                    let summary = LoadedSummary::read(&path).map(|loaded| loaded.summary);
+                    self.warnings = vec![];
+                    self.audit(|log| log.loaded(&path, summary.as_ref().ok()));
+                    self.compare[side] = Some((path, summary));
+                }
+                Task::none()
+            }
+            Message::SelectMode(mode) => {
+                self.mode = mode;
+                if mode == Mode::Archive {
+                    self.search_archive();
+                }
+                Task::none()
+            }
+            Message::SelectAnalysis(analysis) => {
+                self.analysis = analysis;
+                Task::none()
+            }
+            Message::AddFiles => {
+                Task::perform(Self::pick_files("CSV-Datei", &["csv"]), Message::FilesAdded)
+            }
+            Message::AddFolder => Task::perform(Self::pick_folder(), Message::FilesAdded),
+            Message::FilesAdded(paths) => {
+                let (known, known_errors) =
+                    (self.collection.entries.len(), self.collection.errors.len());
+                for path in paths {
+                    self.collection.add_path(&path);
+                }
+                self.warnings = vec![];
+                self.warnings
+                    .extend(
+                        self.collection.errors[known_errors..]
+                            .iter()
+                            .map(|(path, error)| {
+                                format!(
+                                    "{} konnte nicht geladen werden: {error}",
+                                    redaction::shown_path(path, self.redacted)
+                                )
+                            }),
+                    );
+                self.audit_added(known, known_errors);
+                self.warnings
+                    .extend(self.import(&self.collection.entries[known..]));
+                self.refresh_entries();
+                Task::none()
+            }
+            Message::ClearCollection => {
+                self.collection.clear();
+                self.warnings.clear();
+                self.refresh_entries();
+                Task::none()
+            }
+            Message::PickCaseMapping => {
+                Task::perform(Self::pick_files("Zuordnungsdatei", &["enc"]), |paths| {
+                    Message::ReadCaseMapping(paths.into_iter().next().ok_or(()))
+                })
+            }
+            Message::ReadCaseMapping(file) => {
+                if let Ok(path) = file {
+                    self.case_mapping_path = Some(path);
+                    self.case_mapping = None;
+                    self.export_case_numbers = false;
+                }
+                Task::none()
+            }
+            Message::ClearCaseMapping => {
+                self.case_mapping_path = None;
+                self.passphrase.clear();
+                self.case_mapping = None;
+                self.export_case_numbers = false;
+                Task::none()
+            }
+            Message::Passphrase(passphrase) => {
+                self.passphrase = passphrase;
+                Task::none()
+            }
+            Message::UnlockCaseMapping => {
+                if let Some(path) = self.case_mapping_path.clone() {
+                    let mapping = CaseMapping::unlock(&path, &self.passphrase);
+                    self.passphrase.clear();
+                    if mapping.is_ok() {
+                        self.audit(|log| {
+                            log.append(AuditAction::FileLoaded, &path.display().to_string())
+                        });
+                    }
+                    self.case_mapping = Some(mapping);
+                }
+                Task::none()
+            }
+            Message::LockCaseMapping => {
+                self.case_mapping = None;
+                self.export_case_numbers = false;
+                Task::none()
+            }
+            Message::ExportCaseNumbers(export_case_numbers) => {
+                self.export_case_numbers = export_case_numbers;
+                Task::none()
+            }
+            Message::Redact(redacted) => {
+                self.redacted = redacted;
+                Task::none()
+            }
+            Message::IncludeArchive(include_archive) => {
+                self.include_archive = include_archive;
+                self.refresh_entries();
+                Task::none()
+            }
+            Message::ArchiveQuery(query) => {
+                self.archive_query = query;
+                self.search_archive();
+                Task::none()
+            }
+            Message::ShowArchived(index) => {
+                if let Some(archived) = self.archive_results.get(index) {
+                    self.mode = Mode::Viewer;
+                    self.file_path = Some(archived.loaded.source.clone());
+                    self.submission_summary = Some(archived.loaded.summary.clone());
+                    self.file_content = Some(archived.loaded.content.clone());
+                    self.status = Status::FileLoaded;
+                    self.review = Some(archived.review.clone());
+                    self.review_saved = None;
+                    self.warnings.clear();
+                }
+                Task::none()
+            }
+            Message::FilterArchive(filter) => {
+                self.archive_filter = filter;
+                Task::none()
+            }
+            Message::ReviewStatus(status) => {
+                if let Some(review) = &mut self.review {
+                    review.status = status;
+                    self.review_saved = None;
+                }
+                Task::none()
+            }
+            Message::ReviewReviewer(reviewer) => {
+                if let Some(review) = &mut self.review {
+                    review.reviewer = reviewer;
+                    self.review_saved = None;
+                }
+                Task::none()
+            }
+            Message::ReviewNotes(notes) => {
+                if let Some(review) = &mut self.review {
+                    review.notes = notes;
+                    self.review_saved = None;
+                }
+                Task::none()
+            }
+            Message::SaveReview => {
+                self.save_review();
+                Task::none()
+            }
+            Message::FollowUpDays(days) => {
+                if days.is_empty() || days.parse::<u64>().is_ok() {
+                    self.follow_up_days = days;
+                }
+                Task::none()
+            }
+            Message::QcOverdueDays(days) => {
+                if days.is_empty() || days.parse::<u64>().is_ok() {
+                    self.qc_overdue_days = days;
+                }
+                Task::none()
+            }
+            Message::SelectDimension(dimension) => {
+                self.dimension = dimension;
+                Task::none()
+            }
+            Message::AggregateThreshold(threshold) => {
+                if threshold.is_empty() || threshold.parse::<usize>().is_ok() {
+                    self.aggregate_threshold = threshold;
+                }
+                Task::none()
+            }
+            Message::OnlyFindings(only_findings) => {
+                self.only_findings = only_findings;
+                Task::none()
+            }
+            Message::PickSubmissionLog => {
+                Task::perform(Self::pick_file(), Message::ReadSubmissionLog)
+            }
+            Message::ReadSubmissionLog(file) => {
+                if let Ok(path) = file {
+                    let submissions = fs::read_to_string(&path)
+                        .map_err(|_| ())
+                        .and_then(|content| reconciliation::parse_submission_log(&content));
+                    self.audit(|log| {
+                        log.append(AuditAction::FileLoaded, &path.display().to_string())
+                    });
+                    self.submission_log = Some((path, submissions));
+                }
+                Task::none()
+            }
+            Message::PickTariffTable => Task::perform(Self::pick_file(), Message::ReadTariffTable),
+            Message::ReadTariffTable(file) => {
+                if let Ok(path) = file {
+                    let table = fs::read_to_string(&path)
+                        .map_err(|_| ())
+                        .and_then(|content| TariffTable::from_str(&content));
+                    self.audit(|log| {
+                        log.append(AuditAction::FileLoaded, &path.display().to_string())
+                    });
+                    self.tariff_table = Some((path, table));
+                }
+                Task::none()
+            }
+            Message::AddMetadataFiles => Task::perform(
+                Self::pick_files("JSON-Datei", &["json"]),
+                Message::MetadataFilesAdded,
+            ),
+            Message::MetadataFilesAdded(paths) => {
+                for path in paths {
+                    let metadata = fs::read_to_string(&path)
+                        .map_err(|_| ())
+                        .and_then(|content| GrzMetadata::from_str(&content));
+                    self.audit(|log| {
+                        log.append(AuditAction::FileLoaded, &path.display().to_string())
+                    });
+                    self.metadata.push((path, metadata));
+                }
+                Task::none()
+            }
+            Message::ClearMetadata => {
+                self.metadata.clear();
+                Task::none()
+            }
+            Message::Export(export) => {
+                let aggregate = || {
+                    Aggregate::new(
+                        &self.entries,
+                        aggregate::threshold(&self.aggregate_threshold),
+                    )
+                };
+                let (file_name, content) = match export {
+                    Export::BillingCsv => (
+                        "Abrechnungsliste.csv",
+                        Ok(billing::billing_csv(
+                            &billing::classify(&self.entries),
+                            self.tariff_table(),
+                            self.case_mapping().filter(|_| self.export_case_numbers),
+                            self.redacted,
+                        )
+                        .into_bytes()),
+                    ),
+                    Export::AggregateCsv => ("Statistik.csv", Ok(aggregate().csv().into_bytes())),
+                    Export::AggregateJson => {
+                        ("Statistik.json", aggregate().json().map(String::into_bytes))
+                    }
+                    Export::SummaryPdf => ("Meldebestaetigung.pdf", self.summary_pdf()),
+                    Export::SummaryFhir => (
+                        "Meldebestaetigung.fhir.json",
+                        self.submission_summary
+                            .as_ref()
+                            .ok_or(())
+                            .and_then(|summary| {
+                                serde_json::to_string_pretty(&fhir::task(summary, self.redacted))
+                                    .map_err(|_| ())
+                            })
+                            .map(String::into_bytes),
+                    ),
+                    Export::FhirBundle => (
+                        "Meldebestaetigungen.fhir.json",
+                        serde_json::to_string_pretty(&fhir::bundle(&self.entries, self.redacted))
+                            .map_err(|_| ())
+                            .map(String::into_bytes),
+                    ),
+                    Export::TableCsv => (
+                        "Meldebestaetigungen.csv",
+                        Ok(self.summary_table().csv().into_bytes()),
+                    ),
+                    Export::TableXlsx => ("Meldebestaetigungen.xlsx", self.summary_table().xlsx()),
+                };
+                if let Ok(content) = content {
+                    self.export_status = None;
+                    Task::perform(Self::save_file(file_name, content), Message::Exported)
+                } else {
+                    self.export_status = Some(Err(()));
+                    Task::none()
+                }
+            }
+            Message::Exported(result) => {
+                if let Ok(path) = &result {
+                    self.audit(|log| {
+                        log.append(AuditAction::Exported, &path.display().to_string())
+                    });
+                }
+                self.export_status = Some(result);
+                Task::none()
+            }
+            Message::EditorInput(field, value) => {
+                self.editor.set(field, &value);
+                self.editor_export = None;
+                Task::none()
+            }
+            Message::EditorAccepted(accepted) => {
+                self.editor.accepted = accepted;
+                self.editor_export = None;
+                Task::none()
+            }
+            Message::ExportEditor => Task::perform(
+                Self::save_file("Meldebestaetigung.csv", self.editor.csv().into_bytes()),
+                Message::EditorExported,
+            ),
+            Message::EditorExported(result) => {
+                if let Ok(path) = &result {
+                    self.audit(|log| {
+                        log.append(AuditAction::Exported, &path.display().to_string())
+                    });
+                }
+                self.editor_export = Some(result);
+                Task::none()
+            }
             Message::Empty => Task::none(),
         }
     }
 
     fn view(&self) -> Element<'_, Message> {
-        fn colored_content_line<'a>(
-            name: &str,
-            content: &impl CheckedValue,
-            color: Color,
-        ) -> Row<'a, Message> {
-            row![
-                text(name.to_string()).width(160),
-                text_input(name, &content.to_string())
-                    .font(Font::MONOSPACE)
-                    .style(move |theme, status| text_input::Style {
-                        background: Background::Color(color),
-                        placeholder: color!(0x888888),
-                        value: color!(0x333333),
-                        ..text_input::default(theme, status)
-                    })
-            ]
-            .align_y(alignment::Vertical::Center)
-        }
-
-        fn content_line<'a>(name: &str, content: &impl CheckedValue) -> Row<'a, Message> {
-            if content.is_invalid() {
-                return colored_content_line(name, content, color!(0xFFFFCC));
+        column![
+            container(
+                row![
+                    mode_button("Meldebestätigung anzeigen", Mode::Viewer, self.mode),
+                    mode_button("Meldebestätigung erfassen", Mode::Editor, self.mode),
+                    mode_button("Meldebestätigungen vergleichen", Mode::Compare, self.mode),
+                    mode_button("Sammlung auswerten", Mode::Collection, self.mode),
+                    mode_button("Archiv", Mode::Archive, self.mode),
+                    Space::new().width(Length::Fill),
+                    checkbox(self.redacted)
+                        .label("Schwärzen")
+                        .on_toggle(Message::Redact),
+                    self.case_mapping_view(),
+                ]
+                .spacing(4)
+                .align_y(alignment::Vertical::Center)
+            )
+            .padding([4, 12]),
+            match self.mode {
+                Mode::Viewer => self.viewer_view(),
+                Mode::Editor => self.editor_view(),
+                Mode::Compare => self.compare_view(),
+                Mode::Collection => self.collection_view(),
+                Mode::Archive => self.archive_view(),
             }
-            colored_content_line(name, content, Color::WHITE)
+        ]
+        .into()
+    }
+
+    fn case_mapping_view(&self) -> Element<'_, Message> {
+        match (&self.case_mapping_path, &self.case_mapping) {
+            (_, Some(Ok(mapping))) => row![
+                text(format!("{} Fallnummer(n) entsperrt", mapping.len())),
+                button("Sperren")
+                    .style(button::secondary)
+                    .on_press(Message::LockCaseMapping),
+            ],
+            (Some(_), locked) => row![
+                if locked.is_some() {
+                    text("Entsperren fehlgeschlagen").color(color!(0xFF3333))
+                } else {
+                    text("Fallnummern")
+                },
+                text_input("Passphrase", &self.passphrase)
+                    .secure(true)
+                    .on_input(Message::Passphrase)
+                    .on_submit(Message::UnlockCaseMapping)
+                    .width(160),
+                button("Entsperren").on_press(Message::UnlockCaseMapping),
+                button("x")
+                    .style(button::danger)
+                    .on_press(Message::ClearCaseMapping),
+            ],
+            (None, _) => row![
+                button("Fallnummern ..")
+                    .style(button::secondary)
+                    .on_press(Message::PickCaseMapping)
+            ],
         }
+        .spacing(4)
+        .align_y(alignment::Vertical::Center)
+        .into()
+    }
 
+    fn viewer_view(&self) -> Element<'_, Message> {
         let drop_container =
             container(text("Datei hier fallen lassen oder oben auswählen").color(color!(0x777777)))
                 .center(Length::Fill)
@@ -146,14 +793,20 @@ impl Ui {
                 row![
                     match &self.file_path {
                         Some(path) => match self.status {
-                            Status::ParseError => colored_content_line(
+                            Status::ParseError(_) => colored_content_line(
                                 "Meldebestätigung",
-                                &StringValue::new_valid(path.to_str().unwrap_or_default()),
+                                &StringValue::new_valid(&redaction::shown_path(
+                                    path,
+                                    self.redacted
+                                )),
                                 color!(0xFFCCCC)
                             ),
                             _ => content_line(
                                 "Meldebestätigung",
-                                &StringValue::new_valid(path.to_str().unwrap_or_default())
+                                &StringValue::new_valid(&redaction::shown_path(
+                                    path,
+                                    self.redacted
+                                ))
                             ),
                         },
                         _ => content_line(
@@ -167,6 +820,16 @@ impl Ui {
                             .on_press(Message::ClearFile),
                         _ => button("..").on_press(Message::PickFile),
                     },
+                    button("Als PDF speichern").on_press_maybe(
+                        self.submission_summary
+                            .as_ref()
+                            .map(|_| Message::Export(Export::SummaryPdf))
+                    ),
+                    button("Als FHIR speichern").on_press_maybe(
+                        self.submission_summary
+                            .as_ref()
+                            .map(|_| Message::Export(Export::SummaryFhir))
+                    ),
                 ]
                 .spacing(12)
                 .align_y(alignment::Vertical::Center)
@@ -176,72 +839,28 @@ impl Ui {
                 background: Some(Background::Color(color!(0xEEEEEE))),
                 ..container::Style::default()
             }),
+            warnings(&self.warnings),
+            container(export_status(self.export_status.as_ref())).padding([0, 12]),
             rule::horizontal(1),
             match &self.submission_summary {
-                Some(submission_summary) => {
-                    column![
-                        container(text("Inhalt der Meldebestätigung").font(Font {
-                            weight: Weight::Bold,
-                            ..Font::default()
-                        })),
-                        content_line("TAN", &submission_summary.tan),
-                        content_line("Code", &submission_summary.code),
-                        row![
-                            content_line("Datum", &submission_summary.date),
-                            content_line("Laufende Nummer", &submission_summary.counter)
-                        ]
-                        .spacing(80),
-                        content_line("Leistungserbringer", &submission_summary.ik),
-                        content_line("Datenknoten", &submission_summary.datacenter),
-                        content_line("Typ der Meldung", &submission_summary.typ_der_meldung),
-                        content_line("Indikationsbereich", &submission_summary.indikationsbereich),
-                        content_line("Kostenträger", &submission_summary.kostentraeger),
-                        content_line("Art der Daten", &submission_summary.art_der_daten),
-                        if submission_summary
-                            .art_der_sequenzierung
-                            .eq(&ArtDerSequenzierung::Keine)
-                        {
-                            colored_content_line(
-                                "Art der Sequenzierung",
-                                &submission_summary.art_der_sequenzierung,
-                                color!(0xFFFFCC),
-                            )
-                        } else {
-                            content_line(
-                                "Art der Sequenzierung",
-                                &submission_summary.art_der_sequenzierung,
-                            )
-                        },
-                        colored_content_line(
-                            "Qualitätskontrolle",
-                            &StringValue::new_valid(if submission_summary.accepted {
-                                "bestanden"
-                            } else {
-                                "nicht bestanden"
-                            }),
-                            if submission_summary.accepted {
-                                color!(0xCCFFCC)
-                            } else {
-                                color!(0xFFCCCC)
-                            }
+                Some(submission_summary) => column![
+                    scrollable(column![
+                        summary_view(
+                            submission_summary,
+                            self.case_number(submission_summary),
+                            self.redacted
                         ),
-                        colored_content_line(
-                            "Sha256-Hash",
-                            &submission_summary.hash_wert,
-                            if submission_summary.valid_hash() {
-                                color!(0xCCFFCC)
-                            } else {
-                                color!(0xFFCCCC)
-                            }
-                        ),
-                    ]
-                    .padding(12)
-                    .spacing(8)
-                }
+                        self.review_view()
+                    ])
+                    .height(Length::Fill)
+                ],
                 _ => match &self.status {
-                    Status::ParseError => column![
-                        container(text("Fehler beim Lesen der Datei").color(color!(0xFF3333)),)
-                            .center(Length::Fill),
+                    Status::ParseError(error) => column![
+                        container(
+                            text(format!("Fehler beim Lesen der Datei: {error}"))
+                                .color(color!(0xFF3333))
+                        )
+                        .center(Length::Fill),
                         drop_container
                     ]
                     .padding(80),
@@ -252,6 +871,800 @@ impl Ui {
         .into()
     }
 
+    fn editor_view(&self) -> Element<'_, Message> {
+        let editor = &self.editor;
+        let summary = editor.summary();
+
+        column![
+            container(text("Inhalt der Meldebestätigung").font(Font {
+                weight: Weight::Bold,
+                ..Font::default()
+            })),
+            scrollable(editor_fields(editor, &summary)).height(Length::Fill),
+            row![
+                match &summary {
+                    Ok(_) => text("Eingaben ergeben eine gültig aufgebaute Meldebestätigung"),
+                    Err(error) => text(format!(
+                        "Eingaben ergeben keine gültig aufgebaute Meldebestätigung: {error}"
+                    ))
+                    .color(color!(0xFF3333)),
+                }
+                .width(Length::Fill),
+                match &self.editor_export {
+                    Some(Ok(path)) => text(format!("Gespeichert: {}", path.display())),
+                    Some(Err(())) => text("Nicht gespeichert").color(color!(0xFF3333)),
+                    None => text(""),
+                },
+                button("Als CSV-Datei speichern")
+                    .on_press_maybe(summary.is_ok().then_some(Message::ExportEditor)),
+            ]
+            .spacing(12)
+            .align_y(alignment::Vertical::Center),
+        ]
+        .padding(12)
+        .spacing(8)
+        .into()
+    }
+
+    fn compare_view(&self) -> Element<'_, Message> {
+        let file_line = |side: usize| {
+            let (name, content) = match &self.compare[side] {
+                Some((path, Ok(_))) => (redaction::shown_path(path, self.redacted), Color::WHITE),
+                Some((path, Err(_))) => {
+                    (redaction::shown_path(path, self.redacted), color!(0xFFCCCC))
+                }
+                None => ("Keine Datei geladen".to_string(), Color::WHITE),
+            };
+            row![
+                colored_content_line(
+                    if side == 0 { "Links" } else { "Rechts" },
+                    &StringValue::new_valid(&name),
+                    content
+                ),
+                match &self.compare[side] {
+                    Some(_) => button("x")
+                        .style(button::danger)
+                        .on_press(Message::ClearCompareFile(side)),
+                    _ => button("..").on_press(Message::PickCompareFile(side)),
+                },
+            ]
+            .spacing(12)
+            .align_y(alignment::Vertical::Center)
+        };
+
+        column![
+            container(column![file_line(0), file_line(1)].spacing(8))
+                .padding(12)
+                .style(|_| container::Style {
+                    background: Some(Background::Color(color!(0xEEEEEE))),
+                    ..container::Style::default()
+                }),
+            rule::horizontal(1),
+            match &self.compare {
+                [Some((_, Ok(left))), Some((_, Ok(right)))] => {
+                    let lines = compare::compare(left, right)
+                        .iter()
+                        .map(|comparison| comparison_line(comparison, self.redacted))
+                        .collect::<Vec<_>>();
+                    container(scrollable(Column::with_children(lines).spacing(8))).padding(12)
+                }
+                [Some((_, Err(error))), _] | [_, Some((_, Err(error)))] => container(
+                    text(format!("Fehler beim Lesen der Datei: {error}")).color(color!(0xFF3333))
+                )
+                .center(Length::Fill),
+                _ => container(
+                    text("Zwei Dateien oben auswählen oder hier fallen lassen")
+                        .color(color!(0x777777))
+                )
+                .center(Length::Fill),
+            }
+        ]
+        .into()
+    }
+
+    fn collection_view(&self) -> Element<'_, Message> {
+        let errors = if self.collection.errors.is_empty() {
+            String::new()
+        } else {
+            format!(", {} Datei(en) nicht lesbar", self.collection.errors.len())
+        };
+
+        column![
+            container(
+                column![
+                    row![
+                        text(format!(
+                            "{} Meldebestätigung(en) geladen{errors}",
+                            self.collection.entries.len()
+                        ))
+                        .width(Length::Fill),
+                        checkbox(self.include_archive)
+                            .label("Archiv einbeziehen")
+                            .on_toggle(Message::IncludeArchive),
+                        button("Dateien hinzufügen").on_press(Message::AddFiles),
+                        button("Ordner hinzufügen").on_press(Message::AddFolder),
+                        button("Leeren")
+                            .style(button::danger)
+                            .on_press(Message::ClearCollection),
+                    ]
+                    .spacing(12)
+                    .align_y(alignment::Vertical::Center),
+                    row![
+                        export_status(self.export_status.as_ref()),
+                        checkbox(self.export_case_numbers)
+                            .label("Fallnummern exportieren")
+                            .on_toggle_maybe(
+                                self.case_mapping()
+                                    .is_some()
+                                    .then_some(Message::ExportCaseNumbers)
+                            ),
+                        button("Tabelle als CSV").on_press_maybe(
+                            (!self.entries.is_empty()).then_some(Message::Export(Export::TableCsv))
+                        ),
+                        button("Tabelle als XLSX").on_press_maybe(
+                            (!self.entries.is_empty())
+                                .then_some(Message::Export(Export::TableXlsx))
+                        ),
+                        button("FHIR-Bundle").on_press_maybe(
+                            (!self.entries.is_empty())
+                                .then_some(Message::Export(Export::FhirBundle))
+                        ),
+                    ]
+                    .spacing(12)
+                    .align_y(alignment::Vertical::Center),
+                ]
+                .spacing(12)
+            )
+            .padding(12)
+            .style(|_| container::Style {
+                background: Some(Background::Color(color!(0xEEEEEE))),
+                ..container::Style::default()
+            }),
+            warnings(&self.warnings),
+            container(
+                row![
+                    analysis_button("Fallverläufe", Analysis::Chains, self.analysis),
+                    analysis_button("Duplikate", Analysis::Duplicates, self.analysis),
+                    analysis_button("Laufende Nummern", Analysis::Gaps, self.analysis),
+                    analysis_button("Qualitätskontrolle", Analysis::QcFailures, self.analysis),
+                    analysis_button("Abgleich", Analysis::Reconciliation, self.analysis),
+                    analysis_button("GRZ-Metadaten", Analysis::Metadata, self.analysis),
+                    analysis_button("Abrechnung", Analysis::Billing, self.analysis),
+                    analysis_button("Statistik", Analysis::Statistics, self.analysis),
+                ]
+                .spacing(4)
+            )
+            .padding([4, 12]),
+            rule::horizontal(1),
+            if self.entries.is_empty() {
+                container(
+                    text("Dateien oder Ordner oben auswählen oder hier fallen lassen")
+                        .color(color!(0x777777)),
+                )
+                .center(Length::Fill)
+                .into()
+            } else {
+                match self.analysis {
+                    Analysis::Chains => self.chains_view(),
+                    Analysis::Duplicates => self.duplicates_view(),
+                    Analysis::Gaps => self.gaps_view(),
+                    Analysis::QcFailures => self.qc_failures_view(),
+                    Analysis::Reconciliation => self.reconciliation_view(),
+                    Analysis::Metadata => self.metadata_view(),
+                    Analysis::Billing => self.billing_view(),
+                    Analysis::Statistics => self.statistics_view(),
+                }
+            }
+        ]
+        .into()
+    }
+
+    fn archive_view(&self) -> Element<'_, Message> {
+        if self.archive.is_err() {
+            return container(
+                text("Das Archiv konnte nicht geöffnet werden").color(color!(0xFF3333)),
+            )
+            .center(Length::Fill)
+            .into();
+        }
+        let results = self
+            .archive_results
+            .iter()
+            .enumerate()
+            .filter(|(_, archived)| match self.archive_filter {
+                StatusFilter::All => true,
+                StatusFilter::Status(status) => archived.review.status == status,
+            })
+            .map(|(index, archived)| {
+                archived_line(
+                    index,
+                    archived,
+                    self.case_number(&archived.loaded.summary),
+                    self.redacted,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        column![
+            container(
+                row![
+                    text("Suche"),
+                    text_input(
+                        "TAN, Code, Datum, IK, Datenknoten, Dateiname oder Notiz",
+                        &self.archive_query
+                    )
+                    .on_input(Message::ArchiveQuery),
+                    pick_list(
+                        StatusFilter::values(),
+                        Some(self.archive_filter),
+                        Message::FilterArchive
+                    ),
+                ]
+                .spacing(12)
+                .align_y(alignment::Vertical::Center)
+            )
+            .padding(12)
+            .style(|_| container::Style {
+                background: Some(Background::Color(color!(0xEEEEEE))),
+                ..container::Style::default()
+            }),
+            column![
+                text(format!("{} Meldebestätigung(en) gefunden", results.len())),
+                scrollable(Column::with_children(results).spacing(4)).height(Length::Fill),
+            ]
+            .padding(12)
+            .spacing(12),
+        ]
+        .into()
+    }
+
+    fn review_view(&self) -> Element<'_, Message> {
+        let Some(review) = &self.review else {
+            return column![].into();
+        };
+
+        column![
+            container(text("Bearbeitung").font(Font {
+                weight: Weight::Bold,
+                ..Font::default()
+            })),
+            row![
+                text("Status").width(160),
+                pick_list(
+                    ReviewStatus::values(),
+                    Some(review.status),
+                    Message::ReviewStatus
+                )
+                .width(Length::Fill),
+                text("Bearbeiter/in"),
+                text_input("Name", &review.reviewer)
+                    .on_input(Message::ReviewReviewer)
+                    .width(Length::Fill),
+            ]
+            .spacing(12)
+            .align_y(alignment::Vertical::Center),
+            row![
+                text("Notizen").width(160),
+                text_input("Notizen", &review.notes).on_input(Message::ReviewNotes),
+            ]
+            .align_y(alignment::Vertical::Center),
+            row![
+                match self.review_saved {
+                    Some(Ok(())) => text(format!("Gespeichert: {}", review.updated_at)),
+                    Some(Err(())) => text("Nicht gespeichert").color(color!(0xFF3333)),
+                    None if review.updated_at.is_empty() => text(""),
+                    None => text(format!("Zuletzt geändert: {}", review.updated_at)),
+                }
+                .width(Length::Fill),
+                button("Speichern")
+                    .on_press_maybe(self.archive.is_ok().then_some(Message::SaveReview)),
+            ]
+            .align_y(alignment::Vertical::Center),
+        ]
+        .padding(12)
+        .spacing(8)
+        .into()
+    }
+
+    fn chains_view(&self) -> Element<'_, Message> {
+        let chains = chains::chains(
+            &self.entries,
+            Local::now().date_naive(),
+            self.follow_up_days.parse().unwrap_or_default(),
+        )
+        .into_iter()
+        .filter(|chain| !self.only_findings || chain.has_findings())
+        .map(|chain| {
+            let case_number = chain
+                .entries
+                .iter()
+                .find_map(|entry| self.case_number(&entry.loaded.summary));
+            chain_view(&chain, case_number, self.redacted)
+        })
+        .collect::<Vec<_>>();
+
+        column![
+            row![
+                text("Follow-Up erwartet nach"),
+                text_input("Tage", &self.follow_up_days)
+                    .on_input(Message::FollowUpDays)
+                    .width(60),
+                text("Tagen").width(Length::Fill),
+                checkbox(self.only_findings)
+                    .label("Nur Fälle mit Hinweisen")
+                    .on_toggle(Message::OnlyFindings),
+            ]
+            .spacing(8)
+            .align_y(alignment::Vertical::Center),
+            scrollable(Column::with_children(chains).spacing(16)).height(Length::Fill),
+        ]
+        .padding(12)
+        .spacing(12)
+        .into()
+    }
+
+    fn duplicates_view(&self) -> Element<'_, Message> {
+        let duplicates = duplicates::duplicates(&self.entries);
+        if duplicates.is_empty() {
+            return container(text("Keine Duplikate gefunden").color(color!(0x777777)))
+                .center(Length::Fill)
+                .into();
+        }
+
+        scrollable(
+            Column::with_children(
+                duplicates
+                    .iter()
+                    .map(|duplicate| duplicate_view(duplicate, self.redacted)),
+            )
+            .padding(12)
+            .spacing(16),
+        )
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn gaps_view(&self) -> Element<'_, Message> {
+        let sequences = gaps::counter_sequences(&self.entries)
+            .into_iter()
+            .filter(|sequence| !self.only_findings || sequence.has_findings())
+            .map(|sequence| {
+                let mut findings = vec![];
+                if !sequence.missing.is_empty() {
+                    findings.push(format!(
+                        "Fehlend: {}",
+                        gaps::format_counters(&sequence.missing)
+                    ));
+                }
+                if !sequence.repeated.is_empty() {
+                    findings.push(format!(
+                        "Mehrfach: {}",
+                        gaps::format_counters(&sequence.repeated)
+                    ));
+                }
+                column![
+                    text(format!("{} – {}", sequence.date, sequence.ik)).font(Font {
+                        weight: Weight::Bold,
+                        ..Font::default()
+                    }),
+                    row![
+                        text(gaps::format_counters(&sequence.counters)).width(Length::Fill),
+                        text(findings.join("; ")).color(color!(0xCC6600)),
+                    ]
+                    .spacing(8),
+                ]
+                .spacing(4)
+                .into()
+            })
+            .collect::<Vec<Element<'_, Message>>>();
+
+        column![
+            checkbox(self.only_findings)
+                .label("Nur Datumsangaben mit Lücken oder Wiederholungen")
+                .on_toggle(Message::OnlyFindings),
+            scrollable(Column::with_children(sequences).spacing(16)).height(Length::Fill),
+        ]
+        .padding(12)
+        .spacing(12)
+        .into()
+    }
+
+    fn qc_failures_view(&self) -> Element<'_, Message> {
+        let failures = qc::qc_failures(
+            &self.entries,
+            Local::now().date_naive(),
+            self.qc_overdue_days.parse().unwrap_or_default(),
+        );
+        let open = failures.iter().filter(|failure| failure.is_open()).count();
+        let lines = failures
+            .iter()
+            .filter(|failure| !self.only_findings || failure.is_open())
+            .map(|failure| qc_failure_line(failure, self.redacted))
+            .collect::<Vec<_>>();
+
+        column![
+            row![
+                text("Wiederholung erwartet innerhalb von"),
+                text_input("Tage", &self.qc_overdue_days)
+                    .on_input(Message::QcOverdueDays)
+                    .width(60),
+                text("Tagen").width(Length::Fill),
+                checkbox(self.only_findings)
+                    .label("Nur offene")
+                    .on_toggle(Message::OnlyFindings),
+            ]
+            .spacing(8)
+            .align_y(alignment::Vertical::Center),
+            text(format!(
+                "{} nicht bestandene Qualitätskontrollen, davon {open} offen",
+                failures.len()
+            ))
+            .font(Font {
+                weight: Weight::Bold,
+                ..Font::default()
+            }),
+            scrollable(Column::with_children(lines).spacing(4)).height(Length::Fill),
+        ]
+        .padding(12)
+        .spacing(12)
+        .into()
+    }
+
+    fn reconciliation_view(&self) -> Element<'_, Message> {
+        let log_line = row![
+            match &self.submission_log {
+                Some((path, Ok(_))) => content_line(
+                    "Einreichungsprotokoll",
+                    &StringValue::new_valid(&path.display().to_string())
+                ),
+                Some((path, Err(()))) => colored_content_line(
+                    "Einreichungsprotokoll",
+                    &StringValue::new_valid(&path.display().to_string()),
+                    color!(0xFFCCCC)
+                ),
+                None => content_line(
+                    "Einreichungsprotokoll",
+                    &StringValue::new_valid("Keine Datei geladen")
+                ),
+            },
+            button("..").on_press(Message::PickSubmissionLog),
+        ]
+        .spacing(12)
+        .align_y(alignment::Vertical::Center);
+
+        let Some((_, Ok(submissions))) = &self.submission_log else {
+            return column![
+                log_line,
+                container(
+                    text("CSV-Datei mit TAN, Datum, Datenknoten und Meldungstyp auswählen")
+                        .color(color!(0x777777))
+                )
+                .center(Length::Fill)
+            ]
+            .padding(12)
+            .into();
+        };
+
+        let reconciliation = reconciliation::reconcile(submissions, &self.entries);
+
+        column![
+            log_line,
+            scrollable(reconciliation_lists(&reconciliation, self.redacted)).height(Length::Fill),
+        ]
+        .padding(12)
+        .spacing(12)
+        .into()
+    }
+
+    fn metadata_view(&self) -> Element<'_, Message> {
+        let files = self.metadata.iter().map(|(path, metadata)| {
+            let header = text(redaction::shown_path(path, self.redacted)).font(Font {
+                weight: Weight::Bold,
+                ..Font::default()
+            });
+            let Ok(metadata) = metadata else {
+                return column![
+                    header,
+                    text("Fehler beim Lesen der Datei").color(color!(0xFF3333))
+                ]
+                .spacing(4)
+                .into();
+            };
+            let metadata = metadata.mapped();
+            let Some(loaded) = metadata::find_confirmation(&metadata, &self.entries) else {
+                return column![
+                    header,
+                    text(format!(
+                        "Keine Meldebestätigung mit TAN {}",
+                        redaction::shown(&metadata.tan, self.redacted)
+                    ))
+                    .color(color!(0xCC6600))
+                ]
+                .spacing(4)
+                .into();
+            };
+            let mismatches = metadata::cross_check(&metadata, &loaded.summary)
+                .into_iter()
+                .map(|mismatch| {
+                    text(format!(
+                        "{}: eingereicht {}, bestätigt {}",
+                        mismatch.label, mismatch.submitted, mismatch.confirmed
+                    ))
+                    .color(color!(0xCC6600))
+                    .into()
+                })
+                .collect::<Vec<Element<'_, Message>>>();
+            let expected = metadata.expected(&loaded.summary);
+            column![
+                header,
+                text(format!(
+                    "Meldebestätigung: {}",
+                    loaded.shown_name(self.redacted)
+                )),
+                if mismatches.is_empty() {
+                    Column::new().push(text("Keine Abweichungen").color(color!(0x339933)))
+                } else {
+                    Column::with_children(mismatches)
+                },
+                text(format!(
+                    "Erwartet: {}",
+                    redaction::shown(&expected, self.redacted)
+                ))
+                .font(Font::MONOSPACE),
+                text(format!(
+                    "Erhalten: {}",
+                    redaction::shown(loaded.summary.line(), self.redacted)
+                ))
+                .font(Font::MONOSPACE),
+                match metadata::first_difference(&expected, loaded.summary.line()) {
+                    None => text(
+                        "Erhaltene Meldebestätigung entspricht Zeichen für Zeichen der erwarteten"
+                    )
+                    .color(color!(0x339933)),
+                    Some(position) => text(format!(
+                        "Erhaltene Meldebestätigung weicht ab Zeichen {} von der erwarteten ab",
+                        position + 1
+                    ))
+                    .color(color!(0xCC6600)),
+                },
+            ]
+            .spacing(4)
+            .into()
+        });
+
+        column![
+            row![
+                text(format!(
+                    "{} Metadaten-Datei(en) geladen",
+                    self.metadata.len()
+                ))
+                .width(Length::Fill),
+                button("metadata.json hinzufügen").on_press(Message::AddMetadataFiles),
+                button("Leeren")
+                    .style(button::danger)
+                    .on_press(Message::ClearMetadata),
+            ]
+            .spacing(12)
+            .align_y(alignment::Vertical::Center),
+            scrollable(Column::with_children(files).spacing(16)).height(Length::Fill),
+        ]
+        .padding(12)
+        .spacing(12)
+        .into()
+    }
+
+    fn case_mapping(&self) -> Option<&CaseMapping> {
+        match &self.case_mapping {
+            Some(Ok(mapping)) => Some(mapping),
+            _ => None,
+        }
+    }
+
+    fn case_number(&self, summary: &SubmissionSummary) -> Option<&str> {
+        self.case_mapping()
+            .and_then(|mapping| mapping.case_number(summary))
+    }
+
+    fn tariff_table(&self) -> Option<&TariffTable> {
+        match &self.tariff_table {
+            Some((_, Ok(table))) => Some(table),
+            _ => None,
+        }
+    }
+
+    fn billing_view(&self) -> Element<'_, Message> {
+        let items = billing::classify(&self.entries);
+        let table = self.tariff_table();
+        let header = |label: String| {
+            text(label).font(Font {
+                weight: Weight::Bold,
+                ..Font::default()
+            })
+        };
+
+        let groups = billing::groups(&items)
+            .into_iter()
+            .map(|group| {
+                let amount = table.map(|table| {
+                    group
+                        .items
+                        .iter()
+                        .filter_map(|item| table.amount(&item.loaded.summary))
+                        .sum::<Amount>()
+                });
+                row![
+                    text(group.kostentraeger.to_string()).width(100),
+                    text(group.period.clone()).width(80),
+                    text(group.ik.to_string()).width(Length::Fill),
+                    text(format!("{} abrechenbar", group.items.len())),
+                    text(amount.map(|amount| amount.to_string()).unwrap_or_default()),
+                ]
+                .spacing(8)
+                .into()
+            })
+            .collect::<Vec<Element<'_, Message>>>();
+        let excluded = items
+            .iter()
+            .filter(|item| !item.is_billable())
+            .map(|item| excluded_line(item, self.redacted))
+            .collect::<Vec<_>>();
+
+        let mut content = column![].spacing(12);
+        if let Some(table) = table {
+            let totals = tariff::period_totals(&items, table)
+                .into_iter()
+                .map(period_total_line)
+                .collect::<Vec<_>>();
+            content = content
+                .push(header("Erwartete Vergütung nach Zeitraum".to_string()))
+                .push(Column::with_children(totals).spacing(4));
+        }
+        content = content
+            .push(header(format!(
+                "Abrechenbar nach Kostenträger, Zeitraum und Leistungserbringer ({})",
+                items.iter().filter(|item| item.is_billable()).count()
+            )))
+            .push(Column::with_children(groups).spacing(4))
+            .push(header(format!("Nicht abrechenbar ({})", excluded.len())))
+            .push(Column::with_children(excluded).spacing(4));
+
+        column![
+            row![
+                match &self.tariff_table {
+                    Some((path, Ok(_))) => content_line(
+                        "Gebührentabelle",
+                        &StringValue::new_valid(&path.display().to_string())
+                    ),
+                    Some((path, Err(()))) => colored_content_line(
+                        "Gebührentabelle",
+                        &StringValue::new_valid(&path.display().to_string()),
+                        color!(0xFFCCCC)
+                    ),
+                    None => content_line(
+                        "Gebührentabelle",
+                        &StringValue::new_valid("Keine Datei geladen")
+                    ),
+                },
+                button("..").on_press(Message::PickTariffTable),
+            ]
+            .spacing(12)
+            .align_y(alignment::Vertical::Center),
+            row![
+                export_status(self.export_status.as_ref()),
+                checkbox(self.export_case_numbers)
+                    .label("Fallnummern exportieren")
+                    .on_toggle_maybe(
+                        self.case_mapping()
+                            .is_some()
+                            .then_some(Message::ExportCaseNumbers)
+                    ),
+                button("Abrechnungsliste als CSV speichern")
+                    .on_press(Message::Export(Export::BillingCsv)),
+            ]
+            .spacing(12)
+            .align_y(alignment::Vertical::Center),
+            scrollable(content).height(Length::Fill),
+        ]
+        .padding(12)
+        .spacing(12)
+        .into()
+    }
+
+    fn statistics_view(&self) -> Element<'_, Message> {
+        let entries = &self.entries;
+        let header = |label: String| {
+            text(label).font(Font {
+                weight: Weight::Bold,
+                ..Font::default()
+            })
+        };
+
+        let monthly_counts = statistics::monthly_counts(entries, self.dimension);
+        let max = monthly_counts
+            .iter()
+            .flat_map(|month| month.counts.iter().map(|(_, count)| *count))
+            .max()
+            .unwrap_or_default();
+        let counts = monthly_counts.into_iter().map(|month| {
+            let bars = month.counts.into_iter().map(|(value, count)| {
+                row![
+                    text(value).width(200),
+                    bar(count, max, color!(0x3366CC)),
+                    text(count.to_string()).width(40),
+                ]
+                .spacing(8)
+                .align_y(alignment::Vertical::Center)
+                .into()
+            });
+            column![text(month.month), Column::with_children(bars).spacing(2)]
+                .spacing(4)
+                .into()
+        });
+
+        let qc_pass_rates = statistics::qc_pass_rates(entries);
+        let testmeldung_shares = statistics::testmeldung_shares(entries);
+
+        column![
+            row![
+                text("Anzahl nach"),
+                pick_list(
+                    Dimension::values(),
+                    Some(self.dimension),
+                    Message::SelectDimension
+                ),
+            ]
+            .spacing(8)
+            .align_y(alignment::Vertical::Center),
+            row![
+                text("Aggregierter Export, Anzahlen kleiner"),
+                text_input("Anzahl", &self.aggregate_threshold)
+                    .on_input(Message::AggregateThreshold)
+                    .width(60),
+                text(format!(
+                    "unterdrücken (mindestens {})",
+                    aggregate::MIN_THRESHOLD
+                )),
+                export_status(self.export_status.as_ref()),
+                button("CSV").on_press(Message::Export(Export::AggregateCsv)),
+                button("JSON").on_press(Message::Export(Export::AggregateJson)),
+            ]
+            .spacing(8)
+            .align_y(alignment::Vertical::Center),
+            scrollable(
+                column![
+                    header(format!("{} pro Monat", self.dimension)),
+                    Column::with_children(counts).spacing(8),
+                    header(format!(
+                        "Qualitätskontrolle bestanden: {}",
+                        statistics::total(&qc_pass_rates)
+                    )),
+                    Column::with_children(
+                        qc_pass_rates
+                            .iter()
+                            .map(|share| share_line(share, color!(0x339933)))
+                    )
+                    .spacing(2),
+                    header(format!(
+                        "Anteil Testmeldungen: {}",
+                        statistics::total(&testmeldung_shares)
+                    )),
+                    Column::with_children(
+                        testmeldung_shares
+                            .iter()
+                            .map(|share| share_line(share, color!(0xCC6600)))
+                    )
+                    .spacing(2),
+                ]
+                .spacing(12)
+            )
+            .height(Length::Fill),
+        ]
+        .padding(12)
+        .spacing(12)
+        .into()
+    }
+
+    #[allow(clippy::unused_self)]
     fn subscription(&self) -> iced::Subscription<Message> {
         window::events().map(|(_, event)| match event {
             Event::FileDropped(file) => Message::ReadFile(Ok(file)),
@@ -272,10 +1685,759 @@ impl Ui {
         Ok(path.into())
     }
 
-    fn parse_file(&self) -> Result<SubmissionSummary, ()> {
-        match fs::read_to_string(self.file_path.clone().unwrap_or_default()).map_err(|_| ()) {
-            Ok(content) => Ok(SubmissionSummary::from_str(&content)?),
-            Err(()) => Err(()),
+    async fn pick_files(filter: &'static str, extensions: &'static [&'static str]) -> Vec<PathBuf> {
+        rfd::AsyncFileDialog::new()
+            .set_title("Open files...")
+            .add_filter(filter, extensions)
+            .pick_files()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    async fn pick_folder() -> Vec<PathBuf> {
+        rfd::AsyncFileDialog::new()
+            .set_title("Open folder...")
+            .pick_folder()
+            .await
+            .into_iter()
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    async fn save_file(file_name: &'static str, content: Vec<u8>) -> Result<PathBuf, ()> {
+        let extension = Path::new(file_name)
+            .extension()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let path: PathBuf = rfd::AsyncFileDialog::new()
+            .set_title("Save file...")
+            .add_filter(
+                format!("{}-Datei", extension.to_uppercase()),
+                &[extension.as_str()],
+            )
+            .set_file_name(file_name)
+            .save_file()
+            .await
+            .ok_or(())?
+            .into();
+
+        fs::write(&path, content).map_err(|_| ())?;
+        Ok(path)
+    }
+
+    /// Imports Meldebestätigungen into the archive and returns warnings about already archived TANs
+    fn import(&self, entries: &[LoadedSummary]) -> Vec<String> {
+        let Ok(archive) = &self.archive else {
+            return vec![];
+        };
+        let mut warnings = vec![];
+        for loaded in entries {
+            match archive.import(loaded) {
+                Ok(ImportResult::Imported) => {}
+                Ok(ImportResult::AlreadyArchived(imported_at)) => {
+                    warnings.push(format!(
+                        "TAN {} wurde bereits am {imported_at} archiviert",
+                        redaction::shown(loaded.summary.tan().raw_value(), self.redacted)
+                    ));
+                }
+                Err(()) => warnings.push(format!(
+                    "{} konnte nicht archiviert werden",
+                    loaded.shown_name(self.redacted)
+                )),
+            }
+        }
+        warnings
+    }
+
+    /// Writes to the audit log and adds a warning if that fails
+    fn audit(&mut self, write: impl FnOnce(&AuditLog) -> Result<(), ()>) {
+        let written = self.audit_log.as_ref().map_or(Err(()), write);
+        self.audit_written(written);
+    }
+
+    /// Logs the files added to the collection after the given numbers of entries and errors
+    fn audit_added(&mut self, known: usize, known_errors: usize) {
+        let written = self.audit_log.as_ref().map_or(Err(()), |log| {
+            self.collection.entries[known..]
+                .iter()
+                .map(|loaded| log.loaded(&loaded.source, Some(&loaded.summary)))
+                .chain(
+                    self.collection.errors[known_errors..]
+                        .iter()
+                        .map(|(path, _)| log.loaded(path, None)),
+                )
+                .collect()
+        });
+        self.audit_written(written);
+    }
+
+    fn audit_written(&mut self, written: Result<(), ()>) {
+        if written.is_err() {
+            self.warnings
+                .push("Audit-Log konnte nicht geschrieben werden".to_string());
+        }
+    }
+
+    fn load_review(&self, summary: &SubmissionSummary) -> Option<Review> {
+        let archive = self.archive.as_ref().ok()?;
+        let mut review = archive.review(summary.tan().raw_value()).ok()?;
+        if review.reviewer.is_empty() {
+            review.reviewer = audit::user();
+        }
+        Some(review)
+    }
+
+    fn save_review(&mut self) {
+        let (Some(summary), Some(review), Ok(archive)) =
+            (&self.submission_summary, &mut self.review, &self.archive)
+        else {
+            return;
+        };
+        let tan = summary.tan().raw_value().to_string();
+        let previous = archive.review(&tan).unwrap_or_default();
+        review.updated_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let saved = archive.set_review(&tan, review);
+        let details = format!(
+            "TAN {tan}: {} → {}, Bearbeiter/in {}",
+            previous.status, review.status, review.reviewer
+        );
+        self.review_saved = Some(saved);
+        if saved.is_ok() {
+            self.audit(|log| log.append(AuditAction::StatusChanged, &details));
+        }
+    }
+
+    fn search_archive(&mut self) {
+        if let Ok(archive) = &self.archive {
+            self.archive_results = archive.search(&self.archive_query).unwrap_or_default();
+        }
+    }
+
+    fn refresh_entries(&mut self) {
+        self.entries = self.collection.entries.clone();
+        if !self.include_archive {
+            return;
+        }
+        if let Ok(archive) = &self.archive {
+            let archived = archive.entries().unwrap_or_default();
+            let loaded = self
+                .entries
+                .iter()
+                .map(|loaded| loaded.summary.tan().raw_value().to_string())
+                .collect::<Vec<_>>();
+            self.entries.extend(archived.into_iter().filter(|archived| {
+                !loaded
+                    .iter()
+                    .any(|tan| tan == archived.summary.tan().raw_value())
+            }));
         }
     }
+
+    fn summary_table(&self) -> SummaryTable {
+        SummaryTable::new(
+            &self.entries,
+            self.case_mapping().filter(|_| self.export_case_numbers),
+            self.redacted,
+        )
+    }
+
+    /// PDF report of the Meldebestätigung shown in the viewer
+    fn summary_pdf(&self) -> Result<Vec<u8>, ()> {
+        let (Some(summary), Some(path)) = (&self.submission_summary, &self.file_path) else {
+            return Err(());
+        };
+        let file_hash = self
+            .file_content
+            .as_deref()
+            .map(SubmissionSummary::file_hash);
+        Ok(report::pdf(&report::lines(
+            summary,
+            &collection::file_name(path),
+            file_hash.as_deref(),
+            &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            self.redacted,
+        )))
+    }
+}
+
+fn editor_line<'a>(name: &str, field: EditorField, value: &str, invalid: bool) -> Row<'a, Message> {
+    row![
+        text(name.to_string()).width(160),
+        text_input(name, value)
+            .font(Font::MONOSPACE)
+            .on_input(move |value| Message::EditorInput(field, value))
+            .style(move |theme, status| text_input::Style {
+                background: Background::Color(if invalid {
+                    color!(0xFFFFCC)
+                } else {
+                    Color::WHITE
+                }),
+                placeholder: color!(0x888888),
+                value: color!(0x333333),
+                ..text_input::default(theme, status)
+            })
+    ]
+    .spacing(4)
+    .align_y(alignment::Vertical::Center)
+}
+
+fn editor_pick_line<'a, T>(
+    name: &str,
+    field: EditorField,
+    value: &str,
+    values: Vec<T>,
+) -> Row<'a, Message>
+where
+    T: FromStr + RawValue + CheckedValue + Clone + PartialEq + 'a,
+{
+    let selected = T::from_str(value).ok();
+    let invalid = selected.as_ref().is_none_or(CheckedValue::is_invalid);
+    editor_line(name, field, value, invalid).push(
+        pick_list(values, selected, move |value: T| {
+            Message::EditorInput(field, value.raw_value().to_string())
+        })
+        .width(320),
+    )
+}
+
+fn editor_fields<'a>(
+    editor: &Editor,
+    summary: &Result<SubmissionSummary, ParseError>,
+) -> Column<'a, Message> {
+    let invalid = |f: fn(&SubmissionSummary) -> bool| summary.as_ref().is_ok_and(f);
+
+    column![
+        editor_line(
+            "TAN",
+            EditorField::Tan,
+            &editor.tan,
+            invalid(|s| s.tan().is_invalid())
+        ),
+        editor_line(
+            "Code",
+            EditorField::Code,
+            &editor.code,
+            invalid(|s| s.code().is_invalid())
+        ),
+        row![
+            editor_line(
+                "Datum",
+                EditorField::Date,
+                &editor.date,
+                invalid(|s| s.date().is_invalid())
+            ),
+            editor_line(
+                "Laufende Nummer",
+                EditorField::Counter,
+                &editor.counter,
+                invalid(|s| s.counter().is_invalid())
+            ),
+        ]
+        .spacing(80),
+        editor_pick_line(
+            "Leistungserbringer",
+            EditorField::Ik,
+            &editor.ik,
+            Ik::values()
+        ),
+        editor_pick_line(
+            "Datenknoten",
+            EditorField::Datacenter,
+            &editor.datacenter,
+            Datacenter::values()
+        ),
+        editor_pick_line(
+            "Typ der Meldung",
+            EditorField::TypDerMeldung,
+            &editor.typ_der_meldung,
+            TypDerMeldung::values()
+        ),
+        editor_pick_line(
+            "Indikationsbereich",
+            EditorField::Indikationsbereich,
+            &editor.indikationsbereich,
+            Indikationsbereich::values()
+        ),
+        editor_line(
+            "Unbekannter Wert (meist 9)",
+            EditorField::UnknownHashStringValue,
+            &editor.unknown_hash_string_value,
+            false
+        ),
+        editor_pick_line(
+            "Kostenträger",
+            EditorField::Kostentraeger,
+            &editor.kostentraeger,
+            Kostentraeger::values()
+        ),
+        editor_pick_line(
+            "Art der Daten",
+            EditorField::ArtDerDaten,
+            &editor.art_der_daten,
+            ArtDerDaten::values()
+        ),
+        editor_pick_line(
+            "Art der Sequenzierung",
+            EditorField::ArtDerSequenzierung,
+            &editor.art_der_sequenzierung,
+            ArtDerSequenzierung::values()
+        ),
+        row![
+            text("Qualitätskontrolle").width(160),
+            checkbox(editor.accepted)
+                .label("bestanden")
+                .on_toggle(Message::EditorAccepted),
+        ],
+        editor_line(
+            "Unbekanntes Segment (meist 9)",
+            EditorField::UnknownSegment,
+            &editor.unknown_segment,
+            false
+        ),
+        colored_content_line(
+            "Sha256-Hash",
+            &StringValue::new_valid(&editor.hash_wert()),
+            color!(0xCCFFCC)
+        ),
+    ]
+    .spacing(8)
+}
+
+fn mode_button(label: &str, mode: Mode, current: Mode) -> Element<'_, Message> {
+    button(text(label))
+        .style(if mode == current {
+            button::primary
+        } else {
+            button::secondary
+        })
+        .on_press(Message::SelectMode(mode))
+        .into()
+}
+
+fn analysis_button(label: &str, analysis: Analysis, current: Analysis) -> Element<'_, Message> {
+    button(text(label))
+        .style(if analysis == current {
+            button::primary
+        } else {
+            button::secondary
+        })
+        .on_press(Message::SelectAnalysis(analysis))
+        .into()
+}
+
+fn chain_view<'a>(
+    chain: &Chain<'_>,
+    case_number: Option<&str>,
+    redacted: bool,
+) -> Element<'a, Message> {
+    let case_number = case_number
+        .map(|case_number| format!(" – Fall {case_number}"))
+        .unwrap_or_default();
+    let header = text(format!(
+        "Code {} – {}{case_number}",
+        redaction::shown(chain.code, redacted),
+        chain.ik
+    ))
+    .font(Font {
+        weight: Weight::Bold,
+        ..Font::default()
+    });
+    let entries = chain.entries.iter().map(|entry| {
+        let summary = &entry.loaded.summary;
+        row![
+            text(summary.date().to_string()).width(80),
+            text(summary.counter().to_string()).width(40),
+            text(summary.typ_der_meldung().to_string()).width(100),
+            if summary.accepted() {
+                text("QC bestanden").width(120)
+            } else {
+                text("QC nicht bestanden")
+                    .color(color!(0xFF3333))
+                    .width(120)
+            },
+            text(entry.loaded.shown_name(redacted)).width(Length::Fill),
+            match &entry.finding {
+                Some(finding) => text(finding.to_string()).color(color!(0xCC6600)),
+                None => text(""),
+            },
+        ]
+        .spacing(8)
+        .into()
+    });
+
+    column![header, Column::with_children(entries).spacing(4)]
+        .spacing(4)
+        .into()
+}
+
+fn qc_failure_line<'a>(failure: &QcFailure<'_>, redacted: bool) -> Element<'a, Message> {
+    let summary = &failure.loaded.summary;
+    row![
+        text(summary.date().to_string()).width(80),
+        text(summary.counter().to_string()).width(40),
+        text(format!(
+            "Code {}",
+            redaction::shown(summary.code().raw_value(), redacted)
+        ))
+        .width(160),
+        text(failure.loaded.shown_name(redacted)).width(Length::Fill),
+        match failure.state {
+            QcState::Closed(_) => text(failure.state.to_string()).color(color!(0x339933)),
+            QcState::Open(_) => text(failure.state.to_string()),
+            QcState::Overdue(_) => text(failure.state.to_string()).color(color!(0xFF3333)),
+        },
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn duplicate_view<'a>(duplicate: &Duplicate<'_>, redacted: bool) -> Element<'a, Message> {
+    let key = match duplicate.kind {
+        DuplicateKind::Tan | DuplicateKind::HashWert => redaction::shown(&duplicate.key, redacted),
+        DuplicateKind::DateCounterIk => duplicate.key.clone(),
+    };
+    let header = text(format!("{}: {key}", duplicate.kind))
+        .color(color!(0xCC6600))
+        .font(Font {
+            weight: Weight::Bold,
+            ..Font::default()
+        });
+    let entries = duplicate.entries.iter().map(|loaded| {
+        row![
+            text(loaded.summary.date().to_string()).width(80),
+            text(loaded.summary.counter().to_string()).width(40),
+            text(loaded.summary.typ_der_meldung().to_string()).width(100),
+            text(redaction::shown_path(&loaded.source, redacted)).width(Length::Fill),
+        ]
+        .spacing(8)
+        .into()
+    });
+
+    column![header, Column::with_children(entries).spacing(4)]
+        .spacing(4)
+        .into()
+}
+
+fn reconciliation_lists<'a>(
+    reconciliation: &Reconciliation<'_>,
+    redacted: bool,
+) -> Column<'a, Message> {
+    let header = |label: String| {
+        text(label).font(Font {
+            weight: Weight::Bold,
+            ..Font::default()
+        })
+    };
+
+    let confirmed = reconciliation.confirmed.iter().map(|confirmed| {
+        let mismatches = confirmed
+            .mismatches
+            .iter()
+            .map(|mismatch| {
+                format!(
+                    "{}: eingereicht {}, bestätigt {}",
+                    mismatch.label, mismatch.submitted, mismatch.confirmed
+                )
+            })
+            .collect::<Vec<_>>();
+        column![
+            row![
+                text(redaction::shown(&confirmed.submission.tan, redacted))
+                    .font(Font::MONOSPACE)
+                    .width(Length::Fill),
+                text(confirmed.loaded.summary.date().to_string()),
+                text(confirmed.loaded.shown_name(redacted)),
+            ]
+            .spacing(8),
+            if mismatches.is_empty() {
+                text("")
+            } else {
+                text(mismatches.join("; ")).color(color!(0xCC6600))
+            }
+        ]
+        .into()
+    });
+    let pending = reconciliation.pending.iter().map(|submission| {
+        row![
+            text(redaction::shown(&submission.tan, redacted))
+                .font(Font::MONOSPACE)
+                .width(Length::Fill),
+            text(submission.date.to_string()),
+            text(submission.typ_der_meldung.to_string()),
+        ]
+        .spacing(8)
+        .into()
+    });
+    let unmatched = reconciliation.unmatched.iter().map(|loaded| {
+        row![
+            text(redaction::shown(loaded.summary.tan().raw_value(), redacted))
+                .font(Font::MONOSPACE)
+                .width(Length::Fill),
+            text(loaded.shown_name(redacted)),
+        ]
+        .spacing(8)
+        .into()
+    });
+
+    column![
+        header(format!(
+            "Einreichungen mit Meldebestätigung ({})",
+            reconciliation.confirmed_submissions()
+        )),
+        Column::with_children(confirmed).spacing(4),
+        header(format!(
+            "Einreichungen ohne Meldebestätigung ({})",
+            reconciliation.pending.len()
+        )),
+        Column::with_children(pending).spacing(4),
+        header(format!(
+            "Meldebestätigungen ohne Einreichung ({})",
+            reconciliation.unmatched.len()
+        )),
+        Column::with_children(unmatched).spacing(4),
+    ]
+    .spacing(12)
+}
+
+fn excluded_line<'a>(item: &BillingItem<'_>, redacted: bool) -> Element<'a, Message> {
+    row![
+        text(item.loaded.summary.date().to_string()).width(80),
+        text(item.loaded.shown_name(redacted)).width(Length::Fill),
+        text(item.reasons()).color(color!(0xCC6600)),
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn period_total_line<'a>(total: PeriodTotal) -> Element<'a, Message> {
+    row![
+        text(total.period).width(80),
+        text(format!("{} abrechenbar", total.count)).width(120),
+        text(total.amount.to_string()).width(Length::Fill),
+        if total.without_tariff > 0 {
+            text(format!("{} ohne Tarif", total.without_tariff)).color(color!(0xCC6600))
+        } else {
+            text("")
+        },
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn share_line<'a>(share: &MonthlyShare, color: Color) -> Element<'a, Message> {
+    row![
+        text(share.month.clone()).width(80),
+        bar(share.count, share.total, color),
+        text(share.to_string()).width(120),
+    ]
+    .spacing(8)
+    .align_y(alignment::Vertical::Center)
+    .into()
+}
+
+/// Horizontal bar with a length relative to the given maximum
+fn bar<'a>(value: usize, max: usize, color: Color) -> Element<'a, Message> {
+    let portion = |value: usize| u16::try_from(value).unwrap_or(u16::MAX);
+    row![
+        container(text(""))
+            .width(Length::FillPortion(portion(value)))
+            .height(12)
+            .style(move |_| container::Style {
+                background: Some(Background::Color(color)),
+                ..container::Style::default()
+            }),
+        Space::new().width(Length::FillPortion(portion(max.saturating_sub(value)))),
+    ]
+    .width(Length::Fill)
+    .into()
+}
+
+fn archived_line<'a>(
+    index: usize,
+    archived: &ArchivedSummary,
+    case_number: Option<&str>,
+    redacted: bool,
+) -> Element<'a, Message> {
+    let summary = &archived.loaded.summary;
+    row![
+        text(summary.date().to_string()).width(80),
+        text(redaction::shown(summary.tan().raw_value(), redacted)).width(Length::Fill),
+        text(case_number.unwrap_or_default().to_string()).width(120),
+        text(archived.loaded.shown_name(redacted)).width(Length::Fill),
+        text(format!("importiert {}", archived.imported_at)).width(170),
+        text(archived.review.status.to_string()).width(80),
+        if summary.is_valid() {
+            text("gültig").color(color!(0x339933))
+        } else {
+            text("ungültig").color(color!(0xFF3333))
+        }
+        .width(60),
+        button("Anzeigen")
+            .style(button::secondary)
+            .on_press(Message::ShowArchived(index)),
+    ]
+    .spacing(8)
+    .align_y(alignment::Vertical::Center)
+    .into()
+}
+
+fn warnings<'a>(warnings: &[String]) -> Element<'a, Message> {
+    Column::with_children(
+        warnings
+            .iter()
+            .map(|warning| text(warning.clone()).color(color!(0xCC6600)).into()),
+    )
+    .padding(if warnings.is_empty() { 0 } else { 12 })
+    .spacing(4)
+    .into()
+}
+
+fn export_status<'a>(status: Option<&Result<PathBuf, ()>>) -> Element<'a, Message> {
+    match status {
+        Some(Ok(path)) => text(format!("Gespeichert: {}", path.display())),
+        Some(Err(())) => text("Nicht gespeichert").color(color!(0xFF3333)),
+        None => text(""),
+    }
+    .width(Length::Fill)
+    .into()
+}
+
+fn colored_content_line<'a>(
+    name: &str,
+    content: &impl CheckedValue,
+    color: Color,
+) -> Row<'a, Message> {
+    row![
+        text(name.to_string()).width(160),
+        text_input(name, &content.to_string())
+            .font(Font::MONOSPACE)
+            .style(move |theme, status| text_input::Style {
+                background: Background::Color(color),
+                placeholder: color!(0x888888),
+                value: color!(0x333333),
+                ..text_input::default(theme, status)
+            })
+    ]
+    .align_y(alignment::Vertical::Center)
+}
+
+fn comparison_line<'a>(comparison: &FieldComparison, redacted: bool) -> Element<'a, Message> {
+    let differs = comparison.differs();
+    let value = |field: &Field| {
+        let field = redaction::shown_field(field, redacted);
+        let background = if differs {
+            color!(0xFFDDAA)
+        } else if field.invalid {
+            color!(0xFFFFCC)
+        } else {
+            Color::WHITE
+        };
+        text_input(field.label, &field.display)
+            .font(Font::MONOSPACE)
+            .style(move |theme, status| text_input::Style {
+                background: Background::Color(background),
+                placeholder: color!(0x888888),
+                value: color!(0x333333),
+                ..text_input::default(theme, status)
+            })
+    };
+
+    row![
+        text(comparison.label()).width(160),
+        value(&comparison.left),
+        value(&comparison.right),
+    ]
+    .spacing(4)
+    .align_y(alignment::Vertical::Center)
+    .into()
+}
+
+fn content_line<'a>(name: &str, content: &impl CheckedValue) -> Row<'a, Message> {
+    if content.is_invalid() {
+        return colored_content_line(name, content, color!(0xFFFFCC));
+    }
+    colored_content_line(name, content, Color::WHITE)
+}
+
+fn summary_view<'a>(
+    submission_summary: &SubmissionSummary,
+    case_number: Option<&str>,
+    redacted: bool,
+) -> Column<'a, Message> {
+    column![
+        container(text("Inhalt der Meldebestätigung").font(Font {
+            weight: Weight::Bold,
+            ..Font::default()
+        })),
+        content_line(
+            "TAN",
+            &redaction::shown_value(submission_summary.tan(), redacted)
+        ),
+        match case_number {
+            Some(case_number) =>
+                content_line("Interne Fallnummer", &StringValue::new_valid(case_number)),
+            None => row![],
+        },
+        content_line(
+            "Code",
+            &redaction::shown_value(submission_summary.code(), redacted)
+        ),
+        row![
+            content_line("Datum", submission_summary.date()),
+            content_line("Laufende Nummer", submission_summary.counter())
+        ]
+        .spacing(80),
+        content_line("Leistungserbringer", submission_summary.ik()),
+        content_line("Datenknoten", submission_summary.datacenter()),
+        content_line("Typ der Meldung", submission_summary.typ_der_meldung()),
+        content_line(
+            "Indikationsbereich",
+            submission_summary.indikationsbereich()
+        ),
+        content_line("Kostenträger", submission_summary.kostentraeger()),
+        content_line("Art der Daten", submission_summary.art_der_daten()),
+        if submission_summary
+            .art_der_sequenzierung()
+            .eq(&ArtDerSequenzierung::Keine)
+        {
+            colored_content_line(
+                "Art der Sequenzierung",
+                submission_summary.art_der_sequenzierung(),
+                color!(0xFFFFCC),
+            )
+        } else {
+            content_line(
+                "Art der Sequenzierung",
+                submission_summary.art_der_sequenzierung(),
+            )
+        },
+        colored_content_line(
+            "Qualitätskontrolle",
+            &StringValue::new_valid(if submission_summary.accepted() {
+                "bestanden"
+            } else {
+                "nicht bestanden"
+            }),
+            if submission_summary.accepted() {
+                color!(0xCCFFCC)
+            } else {
+                color!(0xFFCCCC)
+            }
+        ),
+        colored_content_line(
+            "Sha256-Hash",
+            &redaction::shown_value(submission_summary.hash_wert(), redacted),
+            if submission_summary.valid_hash() {
+                color!(0xCCFFCC)
+            } else {
+                color!(0xFFCCCC)
+            }
+        ),
+    ]
+    .padding(12)
+    .spacing(8)
 }
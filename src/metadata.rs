@@ -0,0 +1,376 @@
+use crate::collection::LoadedSummary;
+use crate::reconciliation::Mismatch;
+use serde::Deserialize;
+use std::fmt::Display;
+use std::str::FromStr;
+use submission_summary::{
+    ArtDerDaten, ArtDerSequenzierung, Datacenter, Ik, Indikationsbereich, Kostentraeger, RawValue,
+    SubmissionSummary, SubmissionSummaryBuilder, TypDerMeldung,
+};
+
+/// Relevant content of a GRZ submission `metadata.json`
+#[derive(Debug, Deserialize)]
+pub(crate) struct GrzMetadata {
+    pub(crate) submission: MetadataSubmission,
+    #[serde(default)]
+    pub(crate) donors: Vec<MetadataDonor>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MetadataSubmission {
+    pub(crate) submission_date: String,
+    pub(crate) submission_type: String,
+    pub(crate) tan_g: String,
+    pub(crate) coverage_type: String,
+    pub(crate) submitter_id: String,
+    pub(crate) genomic_data_center_id: String,
+    pub(crate) disease_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MetadataDonor {
+    pub(crate) relation: String,
+    #[serde(default)]
+    pub(crate) lab_data: Vec<MetadataLabData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MetadataLabData {
+    pub(crate) library_type: String,
+}
+
+impl FromStr for GrzMetadata {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(|_| ())
+    }
+}
+
+/// Values of a GRZ submission mapped onto the fields of a Meldebestätigung
+#[derive(Debug, PartialEq)]
+pub(crate) struct MappedMetadata {
+    pub(crate) tan: String,
+    pub(crate) date: String,
+    pub(crate) ik: Ik,
+    pub(crate) datacenter: Datacenter,
+    pub(crate) typ_der_meldung: TypDerMeldung,
+    pub(crate) indikationsbereich: Indikationsbereich,
+    pub(crate) kostentraeger: Kostentraeger,
+    pub(crate) art_der_daten: ArtDerDaten,
+    pub(crate) art_der_sequenzierung: ArtDerSequenzierung,
+}
+
+impl GrzMetadata {
+    /// Maps the submission onto the fields of a Meldebestätigung.
+    ///
+    /// The genomic study type (single, duo, trio) does not tell the kind of sequencing,
+    /// therefore the library type of the index patient's lab data is used instead.
+    pub(crate) fn mapped(&self) -> MappedMetadata {
+        let submission = &self.submission;
+        let Ok(ik) = Ik::from_str(&submission.submitter_id);
+        let Ok(datacenter) = Datacenter::from_str(&submission.genomic_data_center_id);
+        MappedMetadata {
+            tan: submission.tan_g.clone(),
+            date: submission.submission_date.clone(),
+            ik,
+            datacenter,
+            typ_der_meldung: match submission.submission_type.as_str() {
+                "initial" => TypDerMeldung::Erstmeldung,
+                "followup" => TypDerMeldung::FollowUp,
+                "addition" => TypDerMeldung::Nachmeldung,
+                "correction" => TypDerMeldung::Korrektur,
+                "test" => TypDerMeldung::Testmeldung,
+                u => TypDerMeldung::Unknown(u.to_string()),
+            },
+            indikationsbereich: match submission.disease_type.as_str() {
+                "oncological" => Indikationsbereich::O,
+                "rare" => Indikationsbereich::R,
+                "hereditary" => Indikationsbereich::H,
+                u => Indikationsbereich::Unknown(u.to_string()),
+            },
+            kostentraeger: match submission.coverage_type.as_str() {
+                "GKV" => Kostentraeger::Gkv,
+                "PKV" => Kostentraeger::Pkv,
+                "BEI" => Kostentraeger::PkvBeihilfe,
+                "BG" | "SEL" | "SOZ" | "GPV" | "PPV" | "SKT" => Kostentraeger::Andere,
+                u => Kostentraeger::Unknown(u.to_string()),
+            },
+            art_der_daten: ArtDerDaten::G,
+            art_der_sequenzierung: match self.index_library_type() {
+                Some("wgs") => ArtDerSequenzierung::Wgs,
+                Some("wes") => ArtDerSequenzierung::Wes,
+                Some("panel") => ArtDerSequenzierung::Panel,
+                Some("wgs_lr") => ArtDerSequenzierung::WgsLr,
+                Some(u) => ArtDerSequenzierung::Unknown(u.to_string()),
+                None => ArtDerSequenzierung::Keine,
+            },
+        }
+    }
+
+    fn index_library_type(&self) -> Option<&str> {
+        self.donors
+            .iter()
+            .find(|donor| donor.relation == "index")
+            .and_then(|donor| donor.lab_data.first())
+            .map(|lab_data| lab_data.library_type.as_str())
+    }
+}
+
+impl MappedMetadata {
+    /// Data line of the Meldebestätigung expected for the submission.
+    ///
+    /// Only TAN, date, Leistungserbringer, Datenknoten, Typ der Meldung, Indikationsbereich,
+    /// Kostenträger, Art der Daten and Art der Sequenzierung are predicted from the metadata.
+    /// Code, laufende Nummer, Qualitätskontrolle and the values of unknown meaning are not part
+    /// of the metadata. They are taken from the received Meldebestätigung and never differ.
+    pub(crate) fn expected(&self, received: &SubmissionSummary) -> String {
+        SubmissionSummaryBuilder::from(received)
+            .tan(&self.tan)
+            .date(&self.date)
+            .ik(&self.ik)
+            .datacenter(&self.datacenter)
+            .typ_der_meldung(&self.typ_der_meldung)
+            .indikationsbereich(&self.indikationsbereich)
+            .kostentraeger(&self.kostentraeger)
+            .art_der_daten(&self.art_der_daten)
+            .art_der_sequenzierung(&self.art_der_sequenzierung)
+            .line()
+    }
+}
+
+/// Position of the first character the received line differs from the expected line
+pub(crate) fn first_difference(expected: &str, received: &str) -> Option<usize> {
+    expected
+        .chars()
+        .zip(received.chars())
+        .position(|(expected, received)| expected != received)
+        .or_else(|| {
+            let (expected, received) = (expected.chars().count(), received.chars().count());
+            (expected != received).then_some(expected.min(received))
+        })
+}
+
+/// Lists all fields the Meldebestätigung differs from the submitted metadata
+pub(crate) fn cross_check(metadata: &MappedMetadata, summary: &SubmissionSummary) -> Vec<Mismatch> {
+    fn check<T: PartialEq + Display>(
+        mismatches: &mut Vec<Mismatch>,
+        label: &'static str,
+        submitted: &T,
+        confirmed: &T,
+    ) {
+        if submitted != confirmed {
+            mismatches.push(Mismatch {
+                label,
+                submitted: submitted.to_string(),
+                confirmed: confirmed.to_string(),
+            });
+        }
+    }
+
+    let mut mismatches = vec![];
+    check(
+        &mut mismatches,
+        "Datum",
+        &metadata.date.as_str(),
+        &summary.date().raw_value(),
+    );
+    check(
+        &mut mismatches,
+        "Leistungserbringer",
+        &metadata.ik,
+        summary.ik(),
+    );
+    check(
+        &mut mismatches,
+        "Datenknoten",
+        &metadata.datacenter,
+        summary.datacenter(),
+    );
+    check(
+        &mut mismatches,
+        "Typ der Meldung",
+        &metadata.typ_der_meldung,
+        summary.typ_der_meldung(),
+    );
+    check(
+        &mut mismatches,
+        "Indikationsbereich",
+        &metadata.indikationsbereich,
+        summary.indikationsbereich(),
+    );
+    check(
+        &mut mismatches,
+        "Kostenträger",
+        &metadata.kostentraeger,
+        summary.kostentraeger(),
+    );
+    check(
+        &mut mismatches,
+        "Art der Daten",
+        &metadata.art_der_daten,
+        summary.art_der_daten(),
+    );
+    check(
+        &mut mismatches,
+        "Art der Sequenzierung",
+        &metadata.art_der_sequenzierung,
+        summary.art_der_sequenzierung(),
+    );
+    mismatches
+}
+
+/// Finds the Meldebestätigung with the TAN of the submission
+pub(crate) fn find_confirmation<'a>(
+    metadata: &MappedMetadata,
+    entries: &'a [LoadedSummary],
+) -> Option<&'a LoadedSummary> {
+    entries.iter().find(|loaded| {
+        loaded
+            .summary
+            .tan()
+            .raw_value()
+            .eq_ignore_ascii_case(&metadata.tan)
+    })
+}
+
+#[cfg(test)]
+pub(crate) const TEST_METADATA: &str = r#"{
+    "schemaVersion": "1.1.7",
+    "submission": {
+        "submissionDate": "2024-07-01",
+        "submissionType": "initial",
+        "tanG": "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31",
+        "localCaseId": "A123456789",
+        "coverageType": "GKV",
+        "submitterId": "260530103",
+        "genomicDataCenterId": "GRZK00001",
+        "clinicalDataNodeId": "KDKK00001",
+        "diseaseType": "oncological",
+        "genomicStudyType": "single",
+        "genomicStudySubtype": "tumor+germline",
+        "labName": "Lab"
+    },
+    "donors": [
+        {
+            "donorPseudonym": "index",
+            "relation": "index",
+            "labData": [{ "libraryType": "wes", "sequenceType": "dna" }]
+        }
+    ]
+}"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    /// Meldebestätigung for [`TEST_METADATA`] confirming another Kostenträger
+    const CONFIRMED_AS_PKV: &str = "Vorgangsnummer,Meldebestaetigung
+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&GRZK00001&0&O&9&2&G&2&1+9+8ad9330b380d196f9ceefda2d6aa6965c3ee489a96400c977bf1106031023c31
+";
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_map_metadata() {
+        let metadata = GrzMetadata::from_str(TEST_METADATA).unwrap();
+
+        assert_eq!(
+            metadata.mapped(),
+            MappedMetadata {
+                tan: "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31".to_string(),
+                date: "2024-07-01".to_string(),
+                ik: Ik::Ik260530103,
+                datacenter: Datacenter::GRZK00001,
+                typ_der_meldung: TypDerMeldung::Erstmeldung,
+                indikationsbereich: Indikationsbereich::O,
+                kostentraeger: Kostentraeger::Gkv,
+                art_der_daten: ArtDerDaten::G,
+                art_der_sequenzierung: ArtDerSequenzierung::Wes,
+            }
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_predict_expected_line() {
+        let metadata = GrzMetadata::from_str(TEST_METADATA).unwrap().mapped();
+        let received = LoadedSummary::from_test_file("meldebestaetigung.csv", CONFIRMED_AS_PKV);
+
+        let expected = metadata.expected(&received.summary);
+
+        assert_eq!(
+            expected,
+            "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&GRZK00001&0&O&9&1&G&2&1+9+56b8dfc01cdccd02e4455555a97561e4797d3256be0710673931951ec7b41a46"
+        );
+        assert_eq!(
+            first_difference(&expected, received.summary.line()),
+            Some(129)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_not_predict_code_counter_and_quality_control() {
+        let metadata = GrzMetadata::from_str(TEST_METADATA).unwrap().mapped();
+        let received = SubmissionSummaryBuilder::default()
+            .tan(&metadata.tan)
+            .code("B987654321")
+            .date(&metadata.date)
+            .counter("002")
+            .ik(&metadata.ik)
+            .datacenter(&metadata.datacenter)
+            .indikationsbereich(&metadata.indikationsbereich)
+            .kostentraeger(&metadata.kostentraeger)
+            .art_der_daten(&metadata.art_der_daten)
+            .art_der_sequenzierung(&metadata.art_der_sequenzierung)
+            .accepted(false)
+            .build()
+            .unwrap();
+
+        let expected = metadata.expected(&received);
+
+        assert_eq!(first_difference(&expected, received.line()), None);
+    }
+
+    #[rstest]
+    #[case("abc", "abc", None)]
+    #[case("abc", "abd", Some(2))]
+    #[case("abc", "ab", Some(2))]
+    #[case("ab", "abc", Some(2))]
+    #[case("äbc", "äbd", Some(2))]
+    fn test_should_find_first_difference(
+        #[case] expected: &str,
+        #[case] received: &str,
+        #[case] position: Option<usize>,
+    ) {
+        assert_eq!(first_difference(expected, received), position);
+    }
+
+    #[test]
+    fn test_should_not_parse_invalid_metadata() {
+        assert!(GrzMetadata::from_str("{\"submission\": {}}").is_err());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_cross_check_metadata() {
+        let metadata = GrzMetadata::from_str(TEST_METADATA).unwrap().mapped();
+        let loaded = LoadedSummary::from_test_file("meldebestaetigung.csv", CONFIRMED_AS_PKV);
+        let entries = [loaded];
+
+        let found = find_confirmation(&metadata, &entries).unwrap();
+
+        assert_eq!(
+            cross_check(&metadata, &found.summary),
+            vec![Mismatch {
+                label: "Kostenträger",
+                submitted: "GKV".to_string(),
+                confirmed: "PKV".to_string(),
+            }]
+        );
+    }
+}
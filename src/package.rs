@@ -0,0 +1,295 @@
+use crate::collection::LoadedSummary;
+use crate::report;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::{Cursor, Read, Write};
+use submission_summary::json::{SummaryJson, ValidationReport};
+use submission_summary::{RawValue, SubmissionSummary};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+const MANIFEST: &str = "manifest.json";
+
+/// Member of an archival package with its checksum
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ManifestEntry {
+    pub(crate) path: String,
+    pub(crate) sha256: String,
+    pub(crate) size: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Manifest {
+    pub(crate) created_at: String,
+    pub(crate) members: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum PackageError {
+    /// The file is no readable ZIP file
+    Unreadable,
+    MissingManifest,
+    /// Member listed within the manifest is missing
+    Missing(String),
+    /// Checksum of the member does not match the manifest
+    Modified(String),
+    /// Member is not listed within the manifest
+    Unlisted(String),
+}
+
+impl Display for PackageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackageError::Unreadable => write!(f, "Paket ist keine lesbare ZIP-Datei"),
+            PackageError::MissingManifest => write!(f, "Manifest fehlt oder ist nicht lesbar"),
+            PackageError::Missing(path) => write!(f, "{path} fehlt"),
+            PackageError::Modified(path) => write!(f, "{path} wurde verändert"),
+            PackageError::Unlisted(path) => write!(f, "{path} ist nicht im Manifest enthalten"),
+        }
+    }
+}
+
+fn pretty(value: &impl Serialize) -> Result<String, ()> {
+    serde_json::to_string_pretty(value).map_err(|_| ())
+}
+
+/// Directory of a Meldebestätigung within the package, prefixed by its position to be unique
+/// even for the same TAN
+fn directory(index: usize, summary: &SubmissionSummary) -> String {
+    format!(
+        "{:04}_{}_{}_{}",
+        index + 1,
+        summary.date().raw_value(),
+        summary.counter().raw_value(),
+        summary.tan().raw_value()
+    )
+    .chars()
+    .map(|c| {
+        if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            c
+        } else {
+            '_'
+        }
+    })
+    .collect()
+}
+
+/// Members of a Meldebestätigung: the original file, the parsed content and validation report as
+/// JSON as well as a text and PDF rendering
+fn members(
+    index: usize,
+    loaded: &LoadedSummary,
+    created_at: &str,
+) -> Result<Vec<(String, Vec<u8>)>, ()> {
+    let summary = &loaded.summary;
+    let directory = directory(index, summary);
+    let lines = report::lines(
+        summary,
+        &loaded.source_name(),
+        Some(&SubmissionSummary::file_hash(&loaded.content)),
+        created_at,
+        false,
+    );
+
+    Ok(vec![
+        (
+            format!("{directory}/original/{}", loaded.source_name()),
+            loaded.content.clone(),
+        ),
+        (
+            format!("{directory}/Meldebestaetigung.json"),
+            pretty(&SummaryJson::from(summary))?.into_bytes(),
+        ),
+        (
+            format!("{directory}/Pruefbericht.json"),
+            pretty(&ValidationReport::from(summary))?.into_bytes(),
+        ),
+        (
+            format!("{directory}/Meldebestaetigung.txt"),
+            report::text_report(&lines).into_bytes(),
+        ),
+        (
+            format!("{directory}/Meldebestaetigung.pdf"),
+            report::pdf(&lines),
+        ),
+    ])
+}
+
+/// Creates a ZIP file containing all Meldebestätigungen with the content they have been parsed
+/// from and a manifest with the SHA-256 of every member
+pub(crate) fn package(entries: &[LoadedSummary], created_at: &str) -> Result<Vec<u8>, ()> {
+    let mut files = vec![];
+    for (index, loaded) in entries.iter().enumerate() {
+        files.extend(members(index, loaded, created_at)?);
+    }
+    let manifest = Manifest {
+        created_at: created_at.to_string(),
+        members: files
+            .iter()
+            .map(|(path, content)| ManifestEntry {
+                path: path.clone(),
+                sha256: SubmissionSummary::file_hash(content),
+                size: content.len(),
+            })
+            .collect(),
+    };
+    files.push((MANIFEST.to_string(), pretty(&manifest)?.into_bytes()));
+
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut zip = ZipWriter::new(Cursor::new(vec![]));
+    for (path, content) in files {
+        zip.start_file(path, options).map_err(|_| ())?;
+        zip.write_all(&content).map_err(|_| ())?;
+    }
+    Ok(zip.finish().map_err(|_| ())?.into_inner())
+}
+
+/// Verifies all members against the manifest and returns the number of members
+pub(crate) fn verify(content: &[u8]) -> Result<usize, PackageError> {
+    let mut zip = ZipArchive::new(Cursor::new(content)).map_err(|_| PackageError::Unreadable)?;
+    let mut contents = BTreeMap::new();
+    for index in 0..zip.len() {
+        let mut file = zip.by_index(index).map_err(|_| PackageError::Unreadable)?;
+        if file.is_dir() {
+            continue;
+        }
+        let mut content = vec![];
+        file.read_to_end(&mut content)
+            .map_err(|_| PackageError::Unreadable)?;
+        contents.insert(file.name().to_string(), content);
+    }
+
+    let manifest = contents
+        .remove(MANIFEST)
+        .and_then(|manifest| serde_json::from_slice::<Manifest>(&manifest).ok())
+        .ok_or(PackageError::MissingManifest)?;
+    for entry in &manifest.members {
+        let content = contents
+            .remove(&entry.path)
+            .ok_or_else(|| PackageError::Missing(entry.path.clone()))?;
+        if SubmissionSummary::file_hash(&content) != entry.sha256 || content.len() != entry.size {
+            return Err(PackageError::Modified(entry.path.clone()));
+        }
+    }
+    if let Some(path) = contents.into_keys().next() {
+        return Err(PackageError::Unlisted(path));
+    }
+    Ok(manifest.members.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &str = "Vorgangsnummer,Meldebestaetigung
+fb69c479d781e043a067bf757b9e0fa4f47a40cedd01c66b83eacfcca1fa6719,IBE+A800000001+A800000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+213b42fdf11586f0e2149cfca5084da982a042a37e889c16f5fc5c13c65a2519
+";
+    const SECOND: &str = "Vorgangsnummer,Meldebestaetigung
+34cff1a66c8b544480abfa291d4ea014f5e4bc28305bd1c9ecc00f8069b47f85,IBE+A800000002+A800000002&20240701002&260620431&KDKK00007&0&O&9&1&C&0&1+9+a2ad9e9cc9f6bab047c99898a0afe47e1047e147392897285832dbea8bb5c133
+";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    /// Path of a member of the Meldebestätigung at the given position within the package
+    fn member(index: usize, content: &str, name: &str) -> String {
+        format!("{}/{name}", directory(index, &loaded(content).summary))
+    }
+
+    #[allow(clippy::unwrap_used)]
+    fn repackage(content: &[u8], change: impl Fn(&str, Vec<u8>) -> Option<Vec<u8>>) -> Vec<u8> {
+        let mut zip = ZipArchive::new(Cursor::new(content)).unwrap();
+        let mut writer = ZipWriter::new(Cursor::new(vec![]));
+        for index in 0..zip.len() {
+            let mut file = zip.by_index(index).unwrap();
+            let mut content = vec![];
+            file.read_to_end(&mut content).unwrap();
+            if let Some(content) = change(file.name(), content) {
+                writer
+                    .start_file(file.name(), SimpleFileOptions::default())
+                    .unwrap();
+                writer.write_all(&content).unwrap();
+            }
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[allow(clippy::unwrap_used)]
+    fn package_of(contents: &[&str]) -> Vec<u8> {
+        let entries = contents
+            .iter()
+            .map(|content| loaded(content))
+            .collect::<Vec<_>>();
+        package(&entries, "2024-08-01 10:00:00").unwrap()
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_create_package() {
+        let content = package_of(&[FIRST, SECOND]);
+        let zip = ZipArchive::new(Cursor::new(content.as_slice())).unwrap();
+        let names = zip.file_names().collect::<Vec<_>>();
+
+        assert_eq!(verify(&content), Ok(10));
+        assert!(names.contains(&"manifest.json"));
+        assert!(names.contains(&member(0, FIRST, "Meldebestaetigung.json").as_str()));
+        assert!(names.contains(&member(1, SECOND, "Pruefbericht.json").as_str()));
+        assert!(
+            names
+                .iter()
+                .any(|name| name.starts_with(&member(1, SECOND, "original/")))
+        );
+    }
+
+    #[test]
+    fn test_should_detect_modified_member() {
+        let modified = member(0, FIRST, "Meldebestaetigung.txt");
+        let content = repackage(&package_of(&[FIRST]), |name, content| {
+            if name == modified {
+                Some(b"changed".to_vec())
+            } else {
+                Some(content)
+            }
+        });
+
+        assert_eq!(verify(&content), Err(PackageError::Modified(modified)));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_detect_missing_and_unlisted_members() {
+        let package = package_of(&[FIRST]);
+        let removed = member(0, FIRST, "Meldebestaetigung.pdf");
+        let missing = repackage(&package, |name, content| {
+            (name != removed).then_some(content)
+        });
+        let mut writer = ZipWriter::new_append(Cursor::new(package)).unwrap();
+        writer
+            .start_file("extra.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"extra").unwrap();
+        let unlisted = writer.finish().unwrap().into_inner();
+
+        assert_eq!(verify(&missing), Err(PackageError::Missing(removed)));
+        assert_eq!(
+            verify(&unlisted),
+            Err(PackageError::Unlisted("extra.txt".to_string()))
+        );
+        assert_eq!(verify(b"no zip"), Err(PackageError::Unreadable));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_package_same_tan_twice() {
+        let content = package_of(&[FIRST, FIRST]);
+        let zip = ZipArchive::new(Cursor::new(content.as_slice())).unwrap();
+        let names = zip.file_names().collect::<Vec<_>>();
+
+        assert_eq!(verify(&content), Ok(10));
+        assert!(names.contains(&member(0, FIRST, "Meldebestaetigung.json").as_str()));
+        assert!(names.contains(&member(1, FIRST, "Meldebestaetigung.json").as_str()));
+    }
+}
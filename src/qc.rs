@@ -0,0 +1,172 @@
+use crate::collection::LoadedSummary;
+use chrono::NaiveDate;
+use std::fmt::Display;
+use submission_summary::RawValue;
+
+/// Meldebestätigung with failed quality control and its resubmission state
+pub(crate) struct QcFailure<'a> {
+    pub(crate) loaded: &'a LoadedSummary,
+    pub(crate) state: QcState<'a>,
+}
+
+impl QcFailure<'_> {
+    pub(crate) fn is_open(&self) -> bool {
+        !matches!(self.state, QcState::Closed(_))
+    }
+}
+
+pub(crate) enum QcState<'a> {
+    /// Closed by a later Meldebestätigung of the same case with passed quality control
+    Closed(&'a LoadedSummary),
+    /// Open for the given number of days
+    Open(i64),
+    /// Open for the given number of days, exceeding the configured limit
+    Overdue(i64),
+}
+
+impl Display for QcState<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QcState::Closed(loaded) => {
+                write!(f, "Erledigt durch Meldung vom {}", loaded.summary.date())
+            }
+            QcState::Open(days) => write!(f, "Offen seit {days} Tagen"),
+            QcState::Overdue(days) => write!(f, "Überfällig, offen seit {days} Tagen"),
+        }
+    }
+}
+
+/// Tracks all Meldebestätigungen with failed quality control.
+///
+/// A failure is closed by a later Meldebestätigung with the same IK and code that passed
+/// quality control. Open failures older than `max_open_days` are reported as overdue.
+pub(crate) fn qc_failures(
+    entries: &[LoadedSummary],
+    today: NaiveDate,
+    max_open_days: i64,
+) -> Vec<QcFailure<'_>> {
+    let order = |loaded: &LoadedSummary| {
+        (
+            loaded.summary.parsed_date(),
+            loaded.summary.parsed_counter(),
+        )
+    };
+
+    let mut failures = entries
+        .iter()
+        .filter(|loaded| !loaded.summary.accepted())
+        .map(|failed| {
+            let resubmission = entries
+                .iter()
+                .filter(|loaded| {
+                    loaded.summary.accepted()
+                        && loaded.summary.ik().raw_value() == failed.summary.ik().raw_value()
+                        && loaded.summary.code().raw_value() == failed.summary.code().raw_value()
+                        && order(loaded) > order(failed)
+                })
+                .min_by_key(|loaded| order(loaded));
+
+            let state = if let Some(loaded) = resubmission {
+                QcState::Closed(loaded)
+            } else {
+                let days = failed
+                    .summary
+                    .parsed_date()
+                    .map(|date| (today - date).num_days())
+                    .unwrap_or_default();
+                if days > max_open_days {
+                    QcState::Overdue(days)
+                } else {
+                    QcState::Open(days)
+                }
+            };
+            QcFailure {
+                loaded: failed,
+                state,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    failures.sort_by_key(|failure| order(failure.loaded));
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAILED: &str = "Vorgangsnummer,Meldebestaetigung
+212f659b346508afcdcb9bfe5a7d4e8caf91cc6dc6d698aa9c728e74ef1f1efb,IBE+A900000001+A900000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&0+9+e91c2ef86e92c334e003408d09ca50c3b97dfbc986df6a0bdfa864ee5d5624cd
+";
+    const PASSED_SAME_DAY: &str = "Vorgangsnummer,Meldebestaetigung
+c37734b5a5f3fde7cd0e919132625d65ae1662583a5926a52796096b949f439b,IBE+A900000001+A900000001&20240701002&260620431&KDKK00007&0&O&9&1&C&0&1+9+34d5b1091c34da5c728f3cbca4a3a07af9dc8f26f2a69688c211f3eafa47a8eb
+";
+    const PASSED_LATER: &str = "Vorgangsnummer,Meldebestaetigung
+6127576fa16fc989f6ecc317f26573c2b1f54b257bdf2dbe60f382aa3d357264,IBE+A900000001+A900000001&20240715001&260620431&KDKK00007&0&O&9&1&C&0&1+9+a2ca4716d3bc2a91f3254e4cdc3e7ffd68897d69f15b65fd889c315ec96a3f98
+";
+    const PASSED_EARLIER: &str = "Vorgangsnummer,Meldebestaetigung
+b3fd11aacc2171ab29167a0ff3e1d7436470f9a304d6af9e5e8a17d0c69e610f,IBE+A900000001+A900000001&20240601001&260620431&KDKK00007&0&O&9&1&C&0&1+9+d5130c752dfeabea8db549bf2b1b38b3309a0c35364f02178989fc77290d6556
+";
+    const OTHER_CASE_PASSED: &str = "Vorgangsnummer,Meldebestaetigung
+93bba18284c7773c653a181c08cabddd95c220ecb45a83a8c097eff62871a606,IBE+A900000002+A900000002&20240715001&260620431&KDKK00007&0&O&9&1&C&0&1+9+5de0c25d0b00ab101f07342abf9cfcfdf16350cb6d3bb21f237805c0421cb1c3
+";
+    const FAILED_IN_MAY: &str = "Vorgangsnummer,Meldebestaetigung
+368d86bfb0ad354893ac90a3e4e95d8eb02a1617155ab6d6a4de6174dadd3084,IBE+A900000001+A900000001&20240501001&260620431&KDKK00007&0&O&9&1&C&0&0+9+c8ebbd35e04c86e7dcffb567cbfd5ddb951c8a97a311a07abbc5bfddf95a5fc0
+";
+    const OTHER_CASE_FAILED: &str = "Vorgangsnummer,Meldebestaetigung
+e48c7d101f26933e0fe20802b0f21cebf902ff624079484095e858daa7ca8e54,IBE+A900000002+A900000002&20240725001&260620431&KDKK00007&0&O&9&1&C&0&0+9+285f013978e501ac50efa0f575d2b3acddc49627e4fe9a0e8da8ef1b921a8ad3
+";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 8, 1).unwrap_or_default()
+    }
+
+    #[test]
+    fn test_should_close_failure_by_later_passed_confirmation() {
+        let entries = vec![
+            loaded(PASSED_SAME_DAY),
+            loaded(FAILED),
+            loaded(PASSED_LATER),
+        ];
+
+        let failures = qc_failures(&entries, today(), 30);
+
+        assert_eq!(failures.len(), 1);
+        assert!(!failures[0].is_open());
+        assert_eq!(
+            failures[0].state.to_string(),
+            "Erledigt durch Meldung vom 2024-07-01"
+        );
+    }
+
+    #[test]
+    fn test_should_not_close_failure_by_earlier_or_other_case() {
+        let entries = vec![
+            loaded(PASSED_EARLIER),
+            loaded(FAILED),
+            loaded(OTHER_CASE_PASSED),
+        ];
+
+        let failures = qc_failures(&entries, today(), 60);
+
+        assert!(matches!(failures[0].state, QcState::Open(31)));
+    }
+
+    #[test]
+    fn test_should_report_overdue_failures() {
+        let entries = vec![loaded(FAILED_IN_MAY), loaded(OTHER_CASE_FAILED)];
+
+        let failures = qc_failures(&entries, today(), 30);
+
+        assert!(matches!(failures[0].state, QcState::Overdue(92)));
+        assert!(matches!(failures[1].state, QcState::Open(7)));
+        assert_eq!(
+            failures[0].state.to_string(),
+            "Überfällig, offen seit 92 Tagen"
+        );
+    }
+}
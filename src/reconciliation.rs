@@ -0,0 +1,321 @@
+use crate::collection::LoadedSummary;
+use crate::csv;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::str::FromStr;
+use submission_summary::{Datacenter, RawValue, TypDerMeldung};
+
+/// Entry of the local submission log
+#[derive(Debug, PartialEq)]
+pub(crate) struct Submission {
+    pub(crate) tan: String,
+    pub(crate) date: NaiveDate,
+    pub(crate) datacenter: Datacenter,
+    pub(crate) typ_der_meldung: TypDerMeldung,
+}
+
+/// Parses a submission log with columns TAN, date, data node and Meldungstyp.
+///
+/// Columns are separated by semicolon if the first line contains one, otherwise by comma.
+/// Values may be quoted, a header line starting with 'TAN' is skipped.
+/// Dates are given as '2024-07-01' or '01.07.2024'.
+/// The Meldungstyp can be given as code or as label, e.g. '0' or 'Erstmeldung'.
+pub(crate) fn parse_submission_log(content: &str) -> Result<Vec<Submission>, ()> {
+    let delimiter = match content.lines().next() {
+        Some(first) if first.contains(';') => ';',
+        _ => ',',
+    };
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .filter(|line| {
+            !line
+                .trim_start_matches('"')
+                .to_uppercase()
+                .starts_with("TAN")
+        })
+        .map(|line| {
+            let parts = csv::split(line, delimiter);
+            let parts = parts.iter().map(|part| part.trim()).collect::<Vec<_>>();
+            if parts.len() != 4 {
+                return Err(());
+            }
+
+            let date = NaiveDate::parse_from_str(parts[1], "%Y-%m-%d")
+                .or_else(|_| NaiveDate::parse_from_str(parts[1], "%d.%m.%Y"))
+                .map_err(|_| ())?;
+            let Ok(datacenter) = Datacenter::from_str(parts[2]);
+            let Ok(typ_der_meldung) = TypDerMeldung::values()
+                .into_iter()
+                .find(|typ| typ.to_string().eq_ignore_ascii_case(parts[3]))
+                .map_or_else(|| TypDerMeldung::from_str(parts[3]), Ok);
+
+            Ok(Submission {
+                tan: parts[0].to_string(),
+                date,
+                datacenter,
+                typ_der_meldung,
+            })
+        })
+        .collect()
+}
+
+/// Attribute of a submission differing from its Meldebestätigung
+#[derive(Debug, PartialEq)]
+pub(crate) struct Mismatch {
+    pub(crate) label: &'static str,
+    pub(crate) submitted: String,
+    pub(crate) confirmed: String,
+}
+
+pub(crate) struct Confirmed<'a> {
+    pub(crate) submission: &'a Submission,
+    pub(crate) loaded: &'a LoadedSummary,
+    pub(crate) mismatches: Vec<Mismatch>,
+}
+
+pub(crate) struct Reconciliation<'a> {
+    pub(crate) confirmed: Vec<Confirmed<'a>>,
+    pub(crate) pending: Vec<&'a Submission>,
+    pub(crate) unmatched: Vec<&'a LoadedSummary>,
+}
+
+impl Reconciliation<'_> {
+    /// Number of submissions with at least one Meldebestätigung
+    pub(crate) fn confirmed_submissions(&self) -> usize {
+        let mut submissions = self
+            .confirmed
+            .iter()
+            .map(|confirmed| confirmed.submission)
+            .collect::<Vec<_>>();
+        submissions.dedup_by(|a, b| std::ptr::eq(*a, *b));
+        submissions.len()
+    }
+}
+
+/// Matches submissions and Meldebestätigungen by TAN. A submission confirmed more than once is
+/// listed with each of its Meldebestätigungen.
+pub(crate) fn reconcile<'a>(
+    submissions: &'a [Submission],
+    entries: &'a [LoadedSummary],
+) -> Reconciliation<'a> {
+    let mut by_tan = HashMap::<String, Vec<&LoadedSummary>>::new();
+    for loaded in entries {
+        by_tan
+            .entry(loaded.summary.tan().raw_value().to_lowercase())
+            .or_default()
+            .push(loaded);
+    }
+
+    let mut confirmed = vec![];
+    let mut pending = vec![];
+    for submission in submissions {
+        match by_tan.get(&submission.tan.to_lowercase()) {
+            Some(matches) => confirmed.extend(matches.iter().map(|loaded| Confirmed {
+                submission,
+                loaded,
+                mismatches: mismatches(submission, loaded),
+            })),
+            None => pending.push(submission),
+        }
+    }
+
+    let unmatched = entries
+        .iter()
+        .filter(|loaded| {
+            !submissions.iter().any(|submission| {
+                submission
+                    .tan
+                    .eq_ignore_ascii_case(loaded.summary.tan().raw_value())
+            })
+        })
+        .collect();
+
+    Reconciliation {
+        confirmed,
+        pending,
+        unmatched,
+    }
+}
+
+fn mismatches(submission: &Submission, loaded: &LoadedSummary) -> Vec<Mismatch> {
+    let summary = &loaded.summary;
+    let mut mismatches = vec![];
+    if summary.parsed_date() != Some(submission.date) {
+        mismatches.push(Mismatch {
+            label: "Datum",
+            submitted: submission.date.to_string(),
+            confirmed: summary.date().to_string(),
+        });
+    }
+    if &submission.datacenter != summary.datacenter() {
+        mismatches.push(Mismatch {
+            label: "Datenknoten",
+            submitted: submission.datacenter.to_string(),
+            confirmed: summary.datacenter().to_string(),
+        });
+    }
+    if &submission.typ_der_meldung != summary.typ_der_meldung() {
+        mismatches.push(Mismatch {
+            label: "Typ der Meldung",
+            submitted: submission.typ_der_meldung.to_string(),
+            confirmed: summary.typ_der_meldung().to_string(),
+        });
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIRMED: &str = "Vorgangsnummer,Meldebestaetigung
+448415941a8f337f3aef13229a358a28cc3050977d7316edc02ef7a169d9662a,IBE+A110000001+A110000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+e8302c9c4125147f67333534e94955870775b5b172ee031c67ae9674cf4bf51e
+";
+    const OTHER_DATACENTER: &str = "Vorgangsnummer,Meldebestaetigung
+8074ccdc525eabfee1b3e8a12d4aa7bd1a6c084d41fe0e70365afe196175b0d3,IBE+A110000002+A110000002&20240701002&260620431&GRZK00001&0&O&9&1&C&0&1+9+602e46ec74c53688fb45b5a122e2d5877375d7097b8b1ffdc12e743e0c50e1d1
+";
+    const NOT_SUBMITTED: &str = "Vorgangsnummer,Meldebestaetigung
+159276ea87d9e937094acda63a283548e7eff35932447938cc2b6ddf77b1bf38,IBE+A110000004+A110000004&20240701004&260620431&KDKK00007&0&O&9&1&C&0&1+9+5144b1419564f72961e19e3bc95fdef8d1dc38a103e0b854a28317ef3616909c
+";
+    /// Same TAN as [`CONFIRMED`] confirmed again on the next day
+    const CONFIRMED_AGAIN: &str = "Vorgangsnummer,Meldebestaetigung
+448415941a8f337f3aef13229a358a28cc3050977d7316edc02ef7a169d9662a,IBE+A110000001+A110000001&20240702001&260620431&KDKK00007&0&O&9&1&C&0&1+9+e6004a8c7e9da7d3da319013bbfe17af73407735b57e7e36be7554cbb84d7711
+";
+    /// TAN of a submission without Meldebestätigung
+    const PENDING_TAN: &str = "14f6e7e9ad0530639f9d942872e90ff6810846837bb68641427f7f445890b951";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_parse_submission_log() {
+        let log = "TAN;Datum;Datenknoten;Meldungstyp\nT1;2024-07-01;KDKK00007;Erstmeldung\n\n\"T2\";\"02.07.2024\";GRZK00001;\"3\"\n";
+
+        assert_eq!(
+            parse_submission_log(log),
+            Ok(vec![
+                Submission {
+                    tan: "T1".to_string(),
+                    date: NaiveDate::from_ymd_opt(2024, 7, 1).unwrap(),
+                    datacenter: Datacenter::KDKK00007,
+                    typ_der_meldung: TypDerMeldung::Erstmeldung,
+                },
+                Submission {
+                    tan: "T2".to_string(),
+                    date: NaiveDate::from_ymd_opt(2024, 7, 2).unwrap(),
+                    datacenter: Datacenter::GRZK00001,
+                    typ_der_meldung: TypDerMeldung::Korrektur,
+                }
+            ])
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_parse_quoted_comma_separated_submission_log() {
+        let log = "\"TAN\",\"Datum\",\"Datenknoten\",\"Meldungstyp\"\n\"T1\",\"2024-07-01\",\"KDKK00007\",\"Erstmeldung\"\n";
+
+        assert_eq!(
+            parse_submission_log(log),
+            Ok(vec![Submission {
+                tan: "T1".to_string(),
+                date: NaiveDate::from_ymd_opt(2024, 7, 1).unwrap(),
+                datacenter: Datacenter::KDKK00007,
+                typ_der_meldung: TypDerMeldung::Erstmeldung,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_should_not_parse_invalid_submission_log() {
+        assert_eq!(parse_submission_log("T1;2024-07-01;KDKK00007"), Err(()));
+        assert_eq!(
+            parse_submission_log("T1;2024-07-01;KDKK00007;0\nT2,2024-07-02,GRZK00001,3"),
+            Err(())
+        );
+        assert_eq!(parse_submission_log("T1;2024-13-01;KDKK00007;0"), Err(()));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_match_german_date_format() {
+        let entries = vec![loaded(CONFIRMED)];
+        let submissions = parse_submission_log(&format!(
+            "{};01.07.2024;KDKK00007;0",
+            entries[0].summary.tan()
+        ))
+        .unwrap();
+
+        let reconciliation = reconcile(&submissions, &entries);
+
+        assert_eq!(reconciliation.confirmed.len(), 1);
+        assert!(reconciliation.confirmed[0].mismatches.is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_reconcile() {
+        let entries = vec![
+            loaded(CONFIRMED),
+            loaded(OTHER_DATACENTER),
+            loaded(NOT_SUBMITTED),
+        ];
+        let submissions = parse_submission_log(&format!(
+            "{},2024-07-01,KDKK00007,0\n{},2024-07-01,KDKK00007,0\n{PENDING_TAN},2024-07-01,KDKK00007,0",
+            entries[0].summary.tan(),
+            entries[1].summary.tan().raw_value().to_uppercase(),
+        ))
+        .unwrap();
+
+        let reconciliation = reconcile(&submissions, &entries);
+
+        assert_eq!(reconciliation.confirmed.len(), 2);
+        assert!(reconciliation.confirmed[0].mismatches.is_empty());
+        assert_eq!(
+            reconciliation.confirmed[1].mismatches,
+            vec![Mismatch {
+                label: "Datenknoten",
+                submitted: "nNGM (KDKK00007)".to_string(),
+                confirmed: "GRZ Köln (GRZK00001)".to_string(),
+            }]
+        );
+        assert_eq!(reconciliation.pending, vec![&submissions[2]]);
+        assert_eq!(reconciliation.unmatched.len(), 1);
+        assert_eq!(
+            reconciliation.unmatched[0].summary.tan(),
+            entries[2].summary.tan()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_list_every_confirmation_of_submission() {
+        let entries = vec![loaded(CONFIRMED), loaded(CONFIRMED_AGAIN)];
+        let submissions = parse_submission_log(&format!(
+            "{},2024-07-01,KDKK00007,0",
+            entries[0].summary.tan()
+        ))
+        .unwrap();
+
+        let reconciliation = reconcile(&submissions, &entries);
+
+        assert_eq!(reconciliation.confirmed.len(), 2);
+        assert_eq!(reconciliation.confirmed_submissions(), 1);
+        assert!(reconciliation.confirmed[0].mismatches.is_empty());
+        assert_eq!(
+            reconciliation.confirmed[1].mismatches,
+            vec![Mismatch {
+                label: "Datum",
+                submitted: "2024-07-01".to_string(),
+                confirmed: "2024-07-02".to_string(),
+            }]
+        );
+        assert!(reconciliation.pending.is_empty());
+        assert!(reconciliation.unmatched.is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::collection;
+use std::path::Path;
+use submission_summary::{CheckedValue, Field, FieldKind, RawValue, StringValue};
+
+/// Number of characters kept at the start and the end of a redacted value
+const VISIBLE: usize = 4;
+
+/// Masks all but the first and last four characters. Values too short to keep any characters
+/// are masked completely.
+pub(crate) fn redact(value: &str) -> String {
+    let chars = value.chars().collect::<Vec<_>>();
+    if chars.len() <= 2 * VISIBLE {
+        return "*".repeat(chars.len());
+    }
+    format!(
+        "{}…{}",
+        chars[..VISIBLE].iter().collect::<String>(),
+        chars[chars.len() - VISIBLE..].iter().collect::<String>()
+    )
+}
+
+/// The value itself or, if redacted, its masked form
+pub(crate) fn shown(value: &str, redacted: bool) -> String {
+    if redacted {
+        redact(value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Path to be shown or, if redacted, its masked file name. File names usually contain the TAN.
+pub(crate) fn shown_path(path: &Path, redacted: bool) -> String {
+    if !redacted {
+        return path.display().to_string();
+    }
+    redact(&collection::file_name(path))
+}
+
+/// Value to be shown with the validation verdict of the original value
+pub(crate) fn shown_value(value: &StringValue, redacted: bool) -> StringValue {
+    StringValue::new(&shown(value.raw_value(), redacted), value.is_invalid())
+}
+
+/// Field to be shown, masking identifying fields like TAN, code and hashes if redacted
+pub(crate) fn shown_field(field: &Field, redacted: bool) -> Field {
+    if !redacted || field.kind != FieldKind::Identifying {
+        return field.clone();
+    }
+    Field {
+        display: redact(&field.display),
+        ..field.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::LoadedSummary;
+    use rstest::rstest;
+
+    const SUMMARY: &str = "Vorgangsnummer,Meldebestaetigung
+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&GRZK00001&0&O&9&1&G&2&1+9+56b8dfc01cdccd02e4455555a97561e4797d3256be0710673931951ec7b41a46
+";
+
+    #[rstest]
+    #[case(
+        "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31",
+        "bad8…2f31"
+    )]
+    #[case("A123456789", "A123…6789")]
+    #[case("T1", "**")]
+    #[case("", "")]
+    fn test_should_redact(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(redact(value), expected);
+    }
+
+    #[test]
+    fn test_should_keep_validation_verdict() {
+        let value = shown_value(&StringValue::new_invalid("A123456789"), true);
+
+        assert_eq!(value.raw_value(), "A123…6789");
+        assert!(value.is_invalid());
+        assert!(!shown_value(&StringValue::new_valid("A123456789"), true).is_invalid());
+    }
+
+    #[test]
+    fn test_should_redact_file_name() {
+        let path = Path::new("/daten/bad8a31b1759b565bee3d283e68af38e.csv");
+
+        assert_eq!(shown_path(path, false), path.display().to_string());
+        assert_eq!(shown_path(path, true), "bad8….csv");
+    }
+
+    #[test]
+    fn test_should_mask_every_identifying_field() {
+        let summary = LoadedSummary::from_test_file("meldebestaetigung.csv", SUMMARY).summary;
+        let identifying = [
+            summary.tan().raw_value(),
+            summary.code().raw_value(),
+            summary.hash_string(),
+            summary.hash_wert().raw_value(),
+        ];
+
+        for field in summary.fields() {
+            let shown = shown_field(&field, true);
+            for value in identifying {
+                assert!(
+                    !shown.display.contains(value),
+                    "{} shows {value}",
+                    field.label
+                );
+            }
+            assert_eq!(shown_field(&field, false), field);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::redaction;
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use submission_summary::SubmissionSummary;
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+const VALUE_OFFSET: f32 = 170.0;
+/// Characters of a value per line using Courier in 9pt
+const VALUE_WIDTH: usize = 50;
+const LINE_HEIGHT: f32 = 13.0;
+
+const RED: (f32, f32, f32) = (0.8, 0.0, 0.0);
+const GREEN: (f32, f32, f32) = (0.0, 0.5, 0.0);
+const BLACK: (f32, f32, f32) = (0.0, 0.0, 0.0);
+
+/// Labeled line of the report
+#[derive(Debug, PartialEq)]
+pub(crate) enum ReportLine {
+    Heading(String),
+    Value {
+        label: String,
+        value: String,
+        /// None if the value is not validated
+        valid: Option<bool>,
+    },
+}
+
+impl ReportLine {
+    fn value(label: &str, value: &str, valid: Option<bool>) -> Self {
+        ReportLine::Value {
+            label: label.to_string(),
+            value: value.to_string(),
+            valid,
+        }
+    }
+}
+
+/// Content of the report including every field, the validation results, the source file and its
+/// SHA-256 as well as the time of export
+pub(crate) fn lines(
+    summary: &SubmissionSummary,
+    source_name: &str,
+    file_hash: Option<&str>,
+    exported_at: &str,
+    redacted: bool,
+) -> Vec<ReportLine> {
+    let verdict = |valid: bool| if valid { "gültig" } else { "ungültig" };
+
+    let mut lines = vec![
+        ReportLine::Heading("Datei".to_string()),
+        ReportLine::value("Dateiname", &redaction::shown(source_name, redacted), None),
+        ReportLine::value(
+            "SHA-256 der Datei",
+            file_hash.unwrap_or("nicht verfügbar"),
+            None,
+        ),
+        ReportLine::value("Exportiert am", exported_at, None),
+        ReportLine::Heading("Inhalt der Meldebestätigung".to_string()),
+    ];
+    lines.extend(summary.fields().iter().map(|field| {
+        let field = redaction::shown_field(field, redacted);
+        ReportLine::value(field.label, &field.display, Some(!field.invalid))
+    }));
+    lines.extend([
+        ReportLine::Heading("Prüfergebnis".to_string()),
+        ReportLine::value(
+            "Meldebestätigung",
+            verdict(summary.is_valid()),
+            Some(summary.is_valid()),
+        ),
+        ReportLine::value(
+            "Hash-Wert",
+            verdict(summary.valid_hash()),
+            Some(summary.valid_hash()),
+        ),
+        ReportLine::value(
+            "Qualitätskontrolle",
+            if summary.accepted() {
+                "bestanden"
+            } else {
+                "nicht bestanden"
+            },
+            Some(summary.accepted()),
+        ),
+    ]);
+    lines
+}
+
+/// Renders the lines as plain text
+pub(crate) fn text_report(lines: &[ReportLine]) -> String {
+    let mut text = vec!["Meldebestätigung nach §64e SGB V".to_string()];
+    for line in lines {
+        match line {
+            ReportLine::Heading(heading) => text.extend([String::new(), heading.clone()]),
+            ReportLine::Value {
+                label,
+                value,
+                valid: Some(false),
+            } => text.push(format!("{label:<24}{value} (fehlerhaft)")),
+            ReportLine::Value { label, value, .. } => text.push(format!("{label:<24}{value}")),
+        }
+    }
+    text.join("\n") + "\n"
+}
+
+/// Renders the lines to a single A4 page using the standard PDF fonts
+pub(crate) fn pdf(lines: &[ReportLine]) -> Vec<u8> {
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let page_id = Ref::new(3);
+    let content_id = Ref::new(4);
+    let info_id = Ref::new(5);
+    let fonts = [
+        (Ref::new(6), Name(b"F1"), Name(b"Helvetica")),
+        (Ref::new(7), Name(b"F2"), Name(b"Helvetica-Bold")),
+        (Ref::new(8), Name(b"F3"), Name(b"Courier")),
+    ];
+
+    let mut content = Content::new();
+    let mut y = PAGE_HEIGHT - MARGIN;
+
+    text(
+        &mut content,
+        Name(b"F2"),
+        16.0,
+        MARGIN,
+        y,
+        "Meldebestätigung nach §64e SGB V",
+    );
+    y -= LINE_HEIGHT;
+    for line in lines {
+        match line {
+            ReportLine::Heading(heading) => {
+                y -= LINE_HEIGHT;
+                content.set_fill_rgb(BLACK.0, BLACK.1, BLACK.2);
+                text(&mut content, Name(b"F2"), 11.0, MARGIN, y, heading);
+                y -= LINE_HEIGHT;
+            }
+            ReportLine::Value {
+                label,
+                value,
+                valid,
+            } => {
+                let (r, g, b) = match valid {
+                    Some(false) => RED,
+                    _ => BLACK,
+                };
+                content.set_fill_rgb(BLACK.0, BLACK.1, BLACK.2);
+                text(&mut content, Name(b"F1"), 10.0, MARGIN, y, label);
+                content.set_fill_rgb(r, g, b);
+                let chars = value.chars().collect::<Vec<_>>();
+                for (index, part) in chars.chunks(VALUE_WIDTH).enumerate() {
+                    if index > 0 {
+                        y -= LINE_HEIGHT;
+                    }
+                    let part = part.iter().collect::<String>();
+                    text(
+                        &mut content,
+                        Name(b"F3"),
+                        9.0,
+                        MARGIN + VALUE_OFFSET,
+                        y,
+                        &part,
+                    );
+                }
+                if let Some(valid) = valid {
+                    let (r, g, b) = if *valid { GREEN } else { RED };
+                    content.set_fill_rgb(r, g, b);
+                    text(
+                        &mut content,
+                        Name(b"F1"),
+                        10.0,
+                        PAGE_WIDTH - MARGIN - 40.0,
+                        y,
+                        if *valid { "ok" } else { "fehlerhaft" },
+                    );
+                }
+                y -= LINE_HEIGHT;
+            }
+        }
+    }
+    let content = content.finish();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids([page_id]).count(1);
+    let mut page = pdf.page(page_id);
+    page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+        .parent(page_tree_id)
+        .contents(content_id);
+    let mut resources = page.resources();
+    let mut font_resources = resources.fonts();
+    for (id, name, _) in fonts {
+        font_resources.pair(name, id);
+    }
+    font_resources.finish();
+    resources.finish();
+    page.finish();
+    for (id, _, base_font) in fonts {
+        pdf.type1_font(id)
+            .base_font(base_font)
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+    }
+    pdf.stream(content_id, &content);
+    pdf.document_info(info_id)
+        .title(TextStr("Meldebestätigung"));
+    pdf.finish()
+}
+
+fn text(content: &mut Content, font: Name, size: f32, x: f32, y: f32, s: &str) {
+    content
+        .begin_text()
+        .set_font(font, size)
+        .next_line(x, y)
+        .show(Str(&win_ansi(s)))
+        .end_text();
+}
+
+/// Encodes text for the standard fonts, replacing characters not available by '?'
+fn win_ansi(s: &str) -> Vec<u8> {
+    s.chars()
+        .map(|c| match c {
+            '€' => 0x80,
+            '…' => 0x85,
+            '–' => 0x96,
+            '—' => 0x97,
+            c if (c as u32) < 0x80 || (0xA0..=0xFF).contains(&(c as u32)) => c as u8,
+            _ => b'?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const ERSTMELDUNG: &str = "Vorgangsnummer,Meldebestaetigung\nbad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31";
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_contain_fields_and_validation() {
+        let summary = SubmissionSummary::from_str(ERSTMELDUNG).unwrap();
+
+        let lines = lines(
+            &summary,
+            "meldung.csv",
+            Some("abc"),
+            "2024-07-02 10:00:00",
+            false,
+        );
+
+        for field in summary.fields() {
+            assert!(lines.iter().any(|line| matches!(
+                line,
+                ReportLine::Value { label, value, .. } if label == field.label && *value == field.display
+            )));
+        }
+        assert!(lines.contains(&ReportLine::value("SHA-256 der Datei", "abc", None)));
+        assert!(lines.contains(&ReportLine::value("Hash-Wert", "gültig", Some(true))));
+        assert!(lines.contains(&ReportLine::value(
+            "Meldebestätigung",
+            "ungültig",
+            Some(false)
+        )));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_create_pdf() {
+        let summary = SubmissionSummary::from_str(ERSTMELDUNG).unwrap();
+        let lines = lines(&summary, "meldung.csv", None, "", true);
+
+        let pdf = pdf(&lines);
+
+        assert!(lines.contains(&ReportLine::value("Dateiname", "meld….csv", None)));
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(!pdf.windows(11).any(|window| window == b"meldung.csv"));
+        assert!(!pdf.windows(10).any(|window| window == b"A123456789"));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_create_text_report() {
+        let summary = SubmissionSummary::from_str(ERSTMELDUNG).unwrap();
+        let lines = lines(&summary, "meldung.csv", None, "", false);
+
+        let text = text_report(&lines);
+
+        assert!(text.contains("\nTyp der Meldung         Erstmeldung\n"));
+        assert!(text.contains("\nMeldebestätigung        ungültig (fehlerhaft)\n"));
+    }
+
+    #[test]
+    fn test_should_encode_win_ansi() {
+        assert_eq!(win_ansi("Prüfung – 5 €"), b"Pr\xfcfung \x96 5 \x80");
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Processing state of a Meldebestätigung within the review workflow
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) enum ReviewStatus {
+    #[default]
+    New,
+    Reviewed,
+    Filed,
+    Billed,
+    Disputed,
+}
+
+impl ReviewStatus {
+    pub(crate) fn values() -> Vec<Self> {
+        vec![
+            ReviewStatus::New,
+            ReviewStatus::Reviewed,
+            ReviewStatus::Filed,
+            ReviewStatus::Billed,
+            ReviewStatus::Disputed,
+        ]
+    }
+
+    /// Value used to store the status
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            ReviewStatus::New => "new",
+            ReviewStatus::Reviewed => "reviewed",
+            ReviewStatus::Filed => "filed",
+            ReviewStatus::Billed => "billed",
+            ReviewStatus::Disputed => "disputed",
+        }
+    }
+}
+
+impl FromStr for ReviewStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ReviewStatus::values()
+            .into_iter()
+            .find(|status| status.key() == s)
+            .ok_or(())
+    }
+}
+
+impl Display for ReviewStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewStatus::New => write!(f, "Neu"),
+            ReviewStatus::Reviewed => write!(f, "Geprüft"),
+            ReviewStatus::Filed => write!(f, "Abgelegt"),
+            ReviewStatus::Billed => write!(f, "Abgerechnet"),
+            ReviewStatus::Disputed => write!(f, "Beanstandet"),
+        }
+    }
+}
+
+/// Review of a Meldebestätigung, stored by TAN
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Review {
+    pub(crate) status: ReviewStatus,
+    pub(crate) reviewer: String,
+    pub(crate) notes: String,
+    pub(crate) updated_at: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_parse_stored_status() {
+        for status in ReviewStatus::values() {
+            assert_eq!(ReviewStatus::from_str(status.key()), Ok(status));
+        }
+        assert_eq!(ReviewStatus::from_str("unknown"), Err(()));
+    }
+}
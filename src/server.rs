@@ -0,0 +1,111 @@
+use serde_json::json;
+use std::io::Read;
+use submission_summary::SubmissionSummary;
+use submission_summary::json::ParsedSummary;
+use tiny_http::{Header, Response, Server};
+
+const PATH: &str = "/validate";
+/// Maximum size of a request body in bytes
+const MAX_BODY: usize = 64 * 1024;
+
+/// Status code and JSON body of a response
+#[derive(Debug, PartialEq)]
+pub(crate) struct Reply {
+    pub(crate) status: u16,
+    pub(crate) body: String,
+}
+
+impl Reply {
+    fn error(status: u16, error: &str) -> Self {
+        Reply {
+            status,
+            body: json!({ "error": error }).to_string(),
+        }
+    }
+}
+
+/// Answers a request by the parsed Meldebestätigung and its validation report. The body may
+/// contain the content of a file or a single line without CSV header.
+pub(crate) fn respond(method: &str, url: &str, body: &[u8]) -> Reply {
+    if url.split('?').next() != Some(PATH) {
+        return Reply::error(404, &format!("Nicht gefunden, verwende POST {PATH}"));
+    }
+    if method != "POST" {
+        return Reply::error(405, "Nur POST wird unterstützt");
+    }
+    if body.len() > MAX_BODY {
+        return Reply::error(413, "Anfrage ist zu groß");
+    }
+    let Ok(body) = std::str::from_utf8(body) else {
+        return Reply::error(400, "Anfrage ist kein gültiges UTF-8");
+    };
+    match SubmissionSummary::from_csv_or_line(body) {
+        Ok(summary) => match serde_json::to_string_pretty(&ParsedSummary::from(&summary)) {
+            Ok(body) => Reply { status: 200, body },
+            Err(_) => Reply::error(500, "Antwort konnte nicht erstellt werden"),
+        },
+        Err(error) => Reply::error(422, &error.to_string()),
+    }
+}
+
+/// Serves requests on localhost until the server fails
+pub(crate) fn serve(port: u16) -> Result<(), ()> {
+    let server = Server::http(("127.0.0.1", port)).map_err(|_| ())?;
+    let content_type = Header::from_bytes("Content-Type", "application/json; charset=utf-8")?;
+
+    for mut request in server.incoming_requests() {
+        let mut body = vec![];
+        let reply = match request
+            .as_reader()
+            .take(MAX_BODY as u64 + 1)
+            .read_to_end(&mut body)
+        {
+            Ok(_) => respond(request.method().as_str(), request.url(), &body),
+            Err(_) => Reply::error(400, "Anfrage konnte nicht gelesen werden"),
+        };
+        let response = Response::from_string(reply.body)
+            .with_status_code(reply.status)
+            .with_header(content_type.clone());
+        if request.respond(response).is_err() {
+            eprintln!("Antwort konnte nicht gesendet werden");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const LINE: &str = "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31";
+
+    #[rstest]
+    #[case(LINE.to_string())]
+    #[case(format!("Vorgangsnummer,Meldebestaetigung\n{LINE}\n"))]
+    fn test_should_respond_with_summary_and_validation(#[case] body: String) {
+        let reply = respond("POST", "/validate", body.as_bytes());
+        let json = serde_json::from_str::<serde_json::Value>(&reply.body).unwrap_or_default();
+
+        assert_eq!(reply.status, 200);
+        assert_eq!(json["summary"]["code"], "A123456789");
+        assert_eq!(json["validation"]["valid"], false);
+        assert_eq!(json["validation"]["validHash"], true);
+    }
+
+    #[rstest]
+    #[case("POST", "/validate", "kaputt", 422)]
+    #[case("GET", "/validate", LINE, 405)]
+    #[case("POST", "/", LINE, 404)]
+    fn test_should_respond_with_error(
+        #[case] method: &str,
+        #[case] url: &str,
+        #[case] body: &str,
+        #[case] status: u16,
+    ) {
+        let reply = respond(method, url, body.as_bytes());
+
+        assert_eq!(reply.status, status);
+        assert!(reply.body.starts_with("{\"error\":"));
+    }
+}
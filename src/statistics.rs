@@ -0,0 +1,210 @@
+use crate::billing;
+use crate::collection::LoadedSummary;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use submission_summary::{SubmissionSummary, TypDerMeldung};
+
+/// Attribute of a Meldebestätigung to count by
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Dimension {
+    Indikationsbereich,
+    ArtDerDaten,
+    ArtDerSequenzierung,
+    Kostentraeger,
+    Datacenter,
+}
+
+impl Dimension {
+    pub(crate) fn values() -> Vec<Self> {
+        vec![
+            Dimension::Indikationsbereich,
+            Dimension::ArtDerDaten,
+            Dimension::ArtDerSequenzierung,
+            Dimension::Kostentraeger,
+            Dimension::Datacenter,
+        ]
+    }
+
+    fn value(self, summary: &SubmissionSummary) -> String {
+        match self {
+            Dimension::Indikationsbereich => summary.indikationsbereich().to_string(),
+            Dimension::ArtDerDaten => summary.art_der_daten().to_string(),
+            Dimension::ArtDerSequenzierung => summary.art_der_sequenzierung().to_string(),
+            Dimension::Kostentraeger => summary.kostentraeger().to_string(),
+            Dimension::Datacenter => summary.datacenter().to_string(),
+        }
+    }
+}
+
+impl Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dimension::Indikationsbereich => write!(f, "Indikationsbereich"),
+            Dimension::ArtDerDaten => write!(f, "Art der Daten"),
+            Dimension::ArtDerSequenzierung => write!(f, "Art der Sequenzierung"),
+            Dimension::Kostentraeger => write!(f, "Kostenträger"),
+            Dimension::Datacenter => write!(f, "Datenknoten"),
+        }
+    }
+}
+
+/// Number of Meldebestätigungen of a month per value of a dimension
+#[derive(Debug, PartialEq)]
+pub(crate) struct MonthlyCounts {
+    pub(crate) month: String,
+    pub(crate) counts: Vec<(String, usize)>,
+}
+
+pub(crate) fn monthly_counts(
+    entries: &[LoadedSummary],
+    dimension: Dimension,
+) -> Vec<MonthlyCounts> {
+    let mut grouped = BTreeMap::<String, BTreeMap<String, usize>>::new();
+    for loaded in entries {
+        *grouped
+            .entry(billing::period(&loaded.summary))
+            .or_default()
+            .entry(dimension.value(&loaded.summary))
+            .or_default() += 1;
+    }
+    grouped
+        .into_iter()
+        .map(|(month, counts)| MonthlyCounts {
+            month,
+            counts: counts.into_iter().collect(),
+        })
+        .collect()
+}
+
+/// Share of Meldebestätigungen of a month matching a condition
+#[derive(Debug, PartialEq)]
+pub(crate) struct MonthlyShare {
+    pub(crate) month: String,
+    pub(crate) count: usize,
+    pub(crate) total: usize,
+}
+
+impl MonthlyShare {
+    pub(crate) fn percent(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let percent = self.count as f64 * 100.0 / self.total as f64;
+        percent
+    }
+}
+
+impl Display for MonthlyShare {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} % ({}/{})",
+            format!("{:.1}", self.percent()).replace('.', ","),
+            self.count,
+            self.total
+        )
+    }
+}
+
+fn monthly_shares(
+    entries: &[LoadedSummary],
+    condition: impl Fn(&SubmissionSummary) -> bool,
+) -> Vec<MonthlyShare> {
+    let mut grouped = BTreeMap::<String, MonthlyShare>::new();
+    for loaded in entries {
+        let month = billing::period(&loaded.summary);
+        let share = grouped
+            .entry(month.clone())
+            .or_insert_with(|| MonthlyShare {
+                month,
+                count: 0,
+                total: 0,
+            });
+        share.total += 1;
+        if condition(&loaded.summary) {
+            share.count += 1;
+        }
+    }
+    grouped.into_values().collect()
+}
+
+/// Share of Meldebestätigungen with passed quality control per month
+pub(crate) fn qc_pass_rates(entries: &[LoadedSummary]) -> Vec<MonthlyShare> {
+    monthly_shares(entries, SubmissionSummary::accepted)
+}
+
+/// Share of Testmeldungen per month
+pub(crate) fn testmeldung_shares(entries: &[LoadedSummary]) -> Vec<MonthlyShare> {
+    monthly_shares(entries, |summary| {
+        summary.typ_der_meldung() == &TypDerMeldung::Testmeldung
+    })
+}
+
+/// Sums up monthly shares for the whole period
+pub(crate) fn total(shares: &[MonthlyShare]) -> MonthlyShare {
+    MonthlyShare {
+        month: "Gesamt".to_string(),
+        count: shares.iter().map(|share| share.count).sum(),
+        total: shares.iter().map(|share| share.total).sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GKV_JULY: &str = "Vorgangsnummer,Meldebestaetigung
+f9086b64b5698c3a3ea2322b16494a9b79e89261f17d361a1f7666cca4bd0567,IBE+A120000001+A120000001&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+527d7ffe4dd34763cbe3caa3edbc895ede69dceabc1aa229eef56b6b2c8dea11
+";
+    const TESTMELDUNG_JULY: &str = "Vorgangsnummer,Meldebestaetigung
+b22033dbbe11ad619dc2f143415975fedf6ebc7559e890ade718af09d2abb0f2,IBE+A120000002+A120000002&20240702001&260620431&KDKK00007&9&O&9&1&C&0&0+9+ad8ec0ac6fab56c1b9338b91c2811cb255d2da35f884f5a8aef22eba8a250c3d
+";
+    const PKV_JULY: &str = "Vorgangsnummer,Meldebestaetigung
+7d4b9509ab1f4c464f32b0f81fdcb4df036aa0c7ecdc053f61104a18704cc19c,IBE+A120000003+A120000003&20240703001&260620431&KDKK00007&0&O&9&2&C&0&1+9+c274748f7a42103d740da3fe5158eb26e81a593b640bf7a033759d931ad18c9b
+";
+    const GKV_AUGUST: &str = "Vorgangsnummer,Meldebestaetigung
+d1cff23f3784724ce97f41286bd668d29c8b5edd6293e20f58ab8933ce4c31b9,IBE+A120000004+A120000004&20240801001&260620431&KDKK00007&0&O&9&1&C&0&1+9+27df324873d52c06405ecb2a58f77008128656e71e20463bc36f13e09b74059a
+";
+
+    fn entries() -> Vec<LoadedSummary> {
+        [GKV_JULY, TESTMELDUNG_JULY, PKV_JULY, GKV_AUGUST]
+            .iter()
+            .map(|content| LoadedSummary::from_test_file("meldebestaetigung.csv", content))
+            .collect()
+    }
+
+    #[test]
+    fn test_should_count_per_month() {
+        assert_eq!(
+            monthly_counts(&entries(), Dimension::Kostentraeger),
+            vec![
+                MonthlyCounts {
+                    month: "2024-07".to_string(),
+                    counts: vec![("GKV".to_string(), 2), ("PKV".to_string(), 1)],
+                },
+                MonthlyCounts {
+                    month: "2024-08".to_string(),
+                    counts: vec![("GKV".to_string(), 1)],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_calculate_qc_pass_rate() {
+        let rates = qc_pass_rates(&entries());
+
+        assert_eq!(rates[0].to_string(), "66,7 % (2/3)");
+        assert_eq!(rates[1].to_string(), "100,0 % (1/1)");
+        assert_eq!(total(&rates).to_string(), "75,0 % (3/4)");
+    }
+
+    #[test]
+    fn test_should_calculate_testmeldung_share() {
+        let shares = testmeldung_shares(&entries());
+
+        assert_eq!((shares[0].count, shares[0].total), (1, 3));
+        assert_eq!((shares[1].count, shares[1].total), (0, 1));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::case_mapping::CaseMapping;
+use crate::collection::LoadedSummary;
+use crate::csv;
+use crate::redaction;
+use rust_xlsxwriter::{Format, Workbook};
+use submission_summary::FieldKind;
+
+/// Flat table of Meldebestätigungen with raw codes, labels and validation results
+#[derive(Debug, PartialEq)]
+pub(crate) struct SummaryTable {
+    pub(crate) header: Vec<String>,
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+impl SummaryTable {
+    /// Internal case numbers are only added if a case mapping is given. If redacted, TAN, code
+    /// and hashes are masked.
+    pub(crate) fn new(
+        entries: &[LoadedSummary],
+        cases: Option<&CaseMapping>,
+        redacted: bool,
+    ) -> Self {
+        let yes_no = |value: bool| if value { "ja" } else { "nein" }.to_string();
+
+        let mut header = vec!["Datei".to_string()];
+        if cases.is_some() {
+            header.push("Fallnummer".to_string());
+        }
+        let mut rows = vec![];
+        for (index, loaded) in entries.iter().enumerate() {
+            let summary = &loaded.summary;
+            let mut row = vec![loaded.shown_name(redacted)];
+            if let Some(cases) = cases {
+                row.push(cases.case_number(summary).unwrap_or_default().to_string());
+            }
+            let fields = summary.fields();
+            for field in &fields {
+                let shown = redaction::shown_field(field, redacted);
+                // Coded fields get an additional column with the label
+                let coded = field.kind == FieldKind::Coded;
+                if index == 0 {
+                    header.push(field.label.to_string());
+                    if coded {
+                        header.push(format!("{} (Bezeichnung)", field.label));
+                    }
+                }
+                row.push(if coded {
+                    field.raw.clone()
+                } else {
+                    shown.display.clone()
+                });
+                if coded {
+                    row.push(shown.display);
+                }
+            }
+            row.extend([
+                yes_no(summary.is_valid()),
+                fields
+                    .iter()
+                    .filter(|field| field.invalid)
+                    .map(|field| field.label)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+                yes_no(summary.valid_hash()),
+            ]);
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            header.clear();
+        } else {
+            header.extend(["Gültig", "Ungültige Felder", "Hash-Wert gültig"].map(String::from));
+        }
+
+        SummaryTable { header, rows }
+    }
+
+    pub(crate) fn csv(&self) -> String {
+        let mut lines = vec![csv::line(&self.header)];
+        lines.extend(self.rows.iter().map(|row| csv::line(row)));
+        lines.join("\n") + "\n"
+    }
+
+    pub(crate) fn xlsx(&self) -> Result<Vec<u8>, ()> {
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.set_name("Meldebestätigungen").map_err(|_| ())?;
+        let bold = Format::new().set_bold();
+        for (column, value) in (0u16..).zip(&self.header) {
+            sheet
+                .write_string_with_format(0, column, value, &bold)
+                .map_err(|_| ())?;
+        }
+        for (row, values) in (1u32..).zip(&self.rows) {
+            for (column, value) in (0u16..).zip(values) {
+                sheet.write_string(row, column, value).map_err(|_| ())?;
+            }
+        }
+        sheet.set_freeze_panes(1, 0).map_err(|_| ())?;
+        sheet.autofit();
+        workbook.save_to_buffer().map_err(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MELDEBESTAETIGUNG: &str = "Vorgangsnummer,Meldebestaetigung
+e6f34467b8e84309533a0e2dcf9e76e9faa6bfe12716d2b3f3255544fdaf52d9,IBE+A123456789+A123456789&20240701001&260620431&KDKK00007&0&O&9&1&C&0&1+9+b437839d3b05694d116435e069232161670da54f83b4448e10305b63d5748717
+";
+
+    fn entries() -> Vec<LoadedSummary> {
+        vec![LoadedSummary::from_test_file(
+            "meldebestaetigung.csv",
+            MELDEBESTAETIGUNG,
+        )]
+    }
+
+    fn value<'a>(table: &'a SummaryTable, column: &str) -> Option<&'a str> {
+        let index = table.header.iter().position(|header| header == column)?;
+        Some(table.rows[0][index].as_str())
+    }
+
+    #[test]
+    fn test_should_contain_code_and_label() {
+        let table = SummaryTable::new(&entries(), None, false);
+
+        assert_eq!(value(&table, "Kostenträger"), Some("1"));
+        assert_eq!(value(&table, "Kostenträger (Bezeichnung)"), Some("GKV"));
+        assert_eq!(
+            value(&table, "TAN"),
+            Some("e6f34467b8e84309533a0e2dcf9e76e9faa6bfe12716d2b3f3255544fdaf52d9")
+        );
+        assert_eq!(value(&table, "TAN (Bezeichnung)"), None);
+        assert_eq!(value(&table, "Hash-Wert gültig"), Some("ja"));
+        assert_eq!(value(&table, "Fallnummer"), None);
+        assert!(table.rows.iter().all(|row| row.len() == table.header.len()));
+    }
+
+    #[test]
+    fn test_should_redact() {
+        let table = SummaryTable::new(&entries(), None, true);
+
+        assert_eq!(value(&table, "TAN"), Some("e6f3…52d9"));
+        assert_eq!(value(&table, "Code"), Some("A123…6789"));
+        assert_eq!(value(&table, "Datei"), Some("meld….csv"));
+        assert!(
+            !table
+                .csv()
+                .contains("e6f34467b8e84309533a0e2dcf9e76e9faa6bfe12716d2b3f3255544fdaf52d9")
+        );
+    }
+
+    #[test]
+    fn test_should_create_xlsx() {
+        let table = SummaryTable::new(&entries(), None, false);
+
+        assert!(table.xlsx().unwrap_or_default().starts_with(b"PK"));
+        assert!(table.csv().starts_with("Datei,TAN,Code,Datum,"));
+    }
+}
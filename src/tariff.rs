@@ -0,0 +1,337 @@
+use crate::billing::{self, BillingItem};
+use crate::csv;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+use submission_summary::{
+    ArtDerDaten, ArtDerSequenzierung, CheckedValue, Indikationsbereich, SubmissionSummary,
+    TypDerMeldung,
+};
+
+/// Amount of money in cents
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Amount(pub(crate) i64);
+
+impl FromStr for Amount {
+    type Err = ();
+
+    /// Parses amounts like '1234,56', '1.234,56' or '1234.56'
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().trim_end_matches('€').trim();
+        if let Some(s) = s.strip_prefix('-') {
+            return Amount::from_str(s).map(|amount| Amount(-amount.0));
+        }
+        let s = if s.contains(',') {
+            s.replace('.', "").replace(',', ".")
+        } else {
+            s.to_string()
+        };
+        let (euro, cent) = s.split_once('.').unwrap_or((&s, "0"));
+        if cent.is_empty() || cent.len() > 2 || !cent.chars().all(|c| c.is_ascii_digit()) {
+            return Err(());
+        }
+        let euro = euro.parse::<i64>().map_err(|_| ())?;
+        let cent = format!("{cent:0<2}").parse::<i64>().map_err(|_| ())?;
+        Ok(Amount(euro * 100 + cent))
+    }
+}
+
+impl Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        write!(
+            f,
+            "{sign}{},{:02} €",
+            (self.0 / 100).abs(),
+            (self.0 % 100).abs()
+        )
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Amount(self.0 + rhs.0)
+    }
+}
+
+impl Sum for Amount {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Amount::default(), Add::add)
+    }
+}
+
+/// Entry of the fee schedule, unset keys match any value
+#[derive(Debug, PartialEq)]
+pub(crate) struct Tariff {
+    pub(crate) art_der_daten: Option<ArtDerDaten>,
+    pub(crate) art_der_sequenzierung: Option<ArtDerSequenzierung>,
+    pub(crate) indikationsbereich: Option<Indikationsbereich>,
+    pub(crate) typ_der_meldung: Option<TypDerMeldung>,
+    pub(crate) valid_from: NaiveDate,
+    pub(crate) valid_to: Option<NaiveDate>,
+    pub(crate) amount: Amount,
+}
+
+impl Tariff {
+    fn matches(&self, summary: &SubmissionSummary, date: NaiveDate) -> bool {
+        fn key_matches<T: PartialEq>(key: Option<&T>, value: &T) -> bool {
+            key.is_none_or(|key| key == value)
+        }
+
+        key_matches(self.art_der_daten.as_ref(), summary.art_der_daten())
+            && key_matches(
+                self.art_der_sequenzierung.as_ref(),
+                summary.art_der_sequenzierung(),
+            )
+            && key_matches(
+                self.indikationsbereich.as_ref(),
+                summary.indikationsbereich(),
+            )
+            && key_matches(self.typ_der_meldung.as_ref(), summary.typ_der_meldung())
+            && self.valid_from <= date
+            && self.valid_to.is_none_or(|valid_to| date <= valid_to)
+    }
+
+    fn specificity(&self) -> usize {
+        [
+            self.art_der_daten.is_some(),
+            self.art_der_sequenzierung.is_some(),
+            self.indikationsbereich.is_some(),
+            self.typ_der_meldung.is_some(),
+        ]
+        .into_iter()
+        .filter(|key| *key)
+        .count()
+    }
+}
+
+/// Header line of the fee schedule
+const HEADER: &str = "Art der Daten;Art der Sequenzierung;Indikationsbereich;Typ der Meldung;Gültig ab;Gültig bis;Betrag";
+
+/// Local fee schedule.
+///
+/// The CSV file contains the columns Art der Daten, Art der Sequenzierung, Indikationsbereich,
+/// Typ der Meldung, valid from, valid to and amount. Keys are given as codes used within the
+/// Meldebestätigung or '*' to match any value. The end of validity may be left empty.
+/// The first line has to be the header line naming these columns.
+#[derive(Debug, Default)]
+pub(crate) struct TariffTable(pub(crate) Vec<Tariff>);
+
+impl FromStr for TariffTable {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        /// Unknown codes are rejected, since they would never match a Meldebestätigung
+        fn key<T: FromStr + CheckedValue>(value: &str) -> Result<Option<T>, ()> {
+            match value {
+                "" | "*" => Ok(None),
+                value => match T::from_str(value) {
+                    Ok(key) if !key.is_invalid() => Ok(Some(key)),
+                    _ => Err(()),
+                },
+            }
+        }
+
+        fn date(value: &str) -> Result<NaiveDate, ()> {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| ())
+        }
+
+        let mut lines = s.lines();
+        if !lines
+            .next()
+            .is_some_and(|line| csv::is_header(line, HEADER, ';'))
+        {
+            return Err(());
+        }
+
+        lines
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let parts = line.split(';').map(str::trim).collect::<Vec<_>>();
+                if parts.len() != 7 {
+                    return Err(());
+                }
+                Ok(Tariff {
+                    art_der_daten: key(parts[0])?,
+                    art_der_sequenzierung: key(parts[1])?,
+                    indikationsbereich: key(parts[2])?,
+                    typ_der_meldung: key(parts[3])?,
+                    valid_from: date(parts[4])?,
+                    valid_to: if parts[5].is_empty() {
+                        None
+                    } else {
+                        Some(date(parts[5])?)
+                    },
+                    amount: Amount::from_str(parts[6])?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(TariffTable)
+    }
+}
+
+impl TariffTable {
+    /// Expected amount using the most specific tariff valid at the date of the Meldebestätigung
+    pub(crate) fn amount(&self, summary: &SubmissionSummary) -> Option<Amount> {
+        let date = summary.parsed_date()?;
+        self.0
+            .iter()
+            .filter(|tariff| tariff.matches(summary, date))
+            .max_by_key(|tariff| tariff.specificity())
+            .map(|tariff| tariff.amount)
+    }
+}
+
+/// Expected reimbursement of all billable Meldebestätigungen within a billing period
+#[derive(Debug, PartialEq)]
+pub(crate) struct PeriodTotal {
+    pub(crate) period: String,
+    pub(crate) count: usize,
+    pub(crate) amount: Amount,
+    pub(crate) without_tariff: usize,
+}
+
+pub(crate) fn period_totals(items: &[BillingItem<'_>], table: &TariffTable) -> Vec<PeriodTotal> {
+    let mut totals = BTreeMap::<String, PeriodTotal>::new();
+    for item in items.iter().filter(|item| item.is_billable()) {
+        let summary = &item.loaded.summary;
+        let period = billing::period(summary);
+        let total = totals.entry(period.clone()).or_insert_with(|| PeriodTotal {
+            period,
+            count: 0,
+            amount: Amount::default(),
+            without_tariff: 0,
+        });
+        total.count += 1;
+        match table.amount(summary) {
+            Some(amount) => total.amount = total.amount + amount,
+            None => total.without_tariff += 1,
+        }
+    }
+    totals.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::LoadedSummary;
+    use rstest::rstest;
+
+    const TABLE: &str = "Art der Daten;Art der Sequenzierung;Indikationsbereich;Typ der Meldung;Gültig ab;Gültig bis;Betrag
+G;*;*;*;2024-01-01;2024-12-31;1.000,00
+G;1;O;0;2024-01-01;2024-12-31;2.500,50
+G;*;*;*;2025-01-01;;1.100,00
+";
+
+    const WGS: &str = "Vorgangsnummer,Meldebestaetigung
+2861f15f49d6021c550e4cf311d6177a6e8ecdd07fabdb342cf806e7981a0808,IBE+A130000001+A130000001&20240701001&260620431&GRZK00001&0&O&9&1&G&1&1+9+f5cc4a9123d855f80fb87ca080439f0b997cd38475d6666081f876511d1f6634
+";
+    const WES: &str = "Vorgangsnummer,Meldebestaetigung
+1da6c7ede0d439e1386f53289decd704ae111ac26782015ed9d74d49e3c794ad,IBE+A130000002+A130000002&20240701002&260620431&GRZK00001&0&O&9&1&G&2&1+9+cf23709fd98dc28c2da4b5de5c722a17036f6d407f75bda293801f6ac2eee58f
+";
+    const WGS_2025: &str = "Vorgangsnummer,Meldebestaetigung
+ddd425d93c4aebc32907b682426f33fd43f75f2c4ce2d5df48067c1d46e4f0fd,IBE+A130000003+A130000003&20250701001&260620431&GRZK00001&0&O&9&1&G&1&1+9+32708d226b55d82bb176c0e8813482059fe261834cc8ebc411201ae407ec71c0
+";
+    const WGS_2023: &str = "Vorgangsnummer,Meldebestaetigung
+90711730bb221559cd7d1b0bb76b187e1e5d4700c7c5714524ea4be48b704af0,IBE+A130000004+A130000004&20230701001&260620431&GRZK00001&0&O&9&1&G&1&1+9+20f29ad7fc8af0b6321bf95f2d4aecc73abe8782e79b74cafa270e1c1c65ee46
+";
+    const WES_FOLLOW_UP: &str = "Vorgangsnummer,Meldebestaetigung
+50a25202c1a9e8ac1be694f1d2c202a36a1508aedf9d8d2c381c28fa95506a4d,IBE+A130000002+A130000002&20240702001&260620431&GRZK00001&1&O&9&1&G&2&1+9+4b8eeea1cdf956df2164d0977970bfb5a492ba7a59ef3f1e297719a63153dd79
+";
+    const WES_TESTMELDUNG: &str = "Vorgangsnummer,Meldebestaetigung
+b7910a1d9a43a21784f2081dd90bf45d9bf2200d00057d7c4141c4792f5a3b36,IBE+A130000006+A130000006&20240703001&260620431&GRZK00001&9&O&9&1&G&2&1+9+5fb935774e16344664b343e044dcb7da9aece1388dadbf1e4f2cdc5e7a947fb3
+";
+    const WES_2023: &str = "Vorgangsnummer,Meldebestaetigung
+725e4173c45d2c5a3f8bad97cee59e199e3dd95f2c74767b520ac293df98dfea,IBE+A130000007+A130000007&20230801001&260620431&GRZK00001&0&O&9&1&G&2&1+9+8eef1129baef0dbaf1e37d15ebad762bb42b4172605bb387d842b4d4bc5bede4
+";
+
+    fn loaded(content: &str) -> LoadedSummary {
+        LoadedSummary::from_test_file("meldebestaetigung.csv", content)
+    }
+
+    #[rstest]
+    #[case("1234,56", Amount(123456))]
+    #[case("1.234,5", Amount(123450))]
+    #[case("1234.56", Amount(123456))]
+    #[case("1234", Amount(123400))]
+    #[case("12,00 €", Amount(1200))]
+    #[case("-0,50", Amount(-50))]
+    fn test_should_parse_amount(#[case] value: &str, #[case] expected: Amount) {
+        assert_eq!(Amount::from_str(value), Ok(expected));
+    }
+
+    #[test]
+    fn test_should_display_amount() {
+        assert_eq!(Amount(123456).to_string(), "1234,56 €");
+        assert_eq!(Amount(-50).to_string(), "-0,50 €");
+    }
+
+    #[rstest]
+    #[case("G;*;*;*;2024-01-01;;abc")]
+    #[case("X;*;*;*;2024-01-01;;1000")]
+    #[case("G;7;*;*;2024-01-01;;1000")]
+    #[case("G;*;o;*;2024-01-01;;1000")]
+    #[case("G;*;*;Erstmeldung;2024-01-01;;1000")]
+    fn test_should_not_parse_invalid_table(#[case] line: &str) {
+        assert!(TariffTable::from_str(&format!("{HEADER}\n{line}")).is_err());
+    }
+
+    #[rstest]
+    #[case("G;*;*;*;2024-01-01;;1000\nG;*;*;*;2025-01-01;;1100")]
+    #[case("Art der Daten;Betrag\nG;*;*;*;2024-01-01;;1000")]
+    #[case("")]
+    fn test_should_not_parse_table_without_header(#[case] table: &str) {
+        assert!(TariffTable::from_str(table).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_use_most_specific_valid_tariff() {
+        let table = TariffTable::from_str(TABLE).unwrap();
+
+        assert_eq!(table.amount(&loaded(WGS).summary), Some(Amount(250050)));
+        assert_eq!(table.amount(&loaded(WES).summary), Some(Amount(100000)));
+        assert_eq!(
+            table.amount(&loaded(WGS_2025).summary),
+            Some(Amount(110000))
+        );
+        assert_eq!(table.amount(&loaded(WGS_2023).summary), None);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_sum_billable_items_per_period() {
+        let table = TariffTable::from_str(TABLE).unwrap();
+        let entries = vec![
+            loaded(WGS),
+            loaded(WES_FOLLOW_UP),
+            loaded(WES_TESTMELDUNG),
+            loaded(WES_2023),
+        ];
+        let items = billing::classify(&entries);
+
+        assert_eq!(
+            period_totals(&items, &table),
+            vec![
+                PeriodTotal {
+                    period: "2023-08".to_string(),
+                    count: 1,
+                    amount: Amount(0),
+                    without_tariff: 1,
+                },
+                PeriodTotal {
+                    period: "2024-07".to_string(),
+                    count: 2,
+                    amount: Amount(350050),
+                    without_tariff: 0,
+                },
+            ]
+        );
+    }
+}
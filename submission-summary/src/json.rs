@@ -0,0 +1,181 @@
+//! Serializable representations of a Meldebestätigung and its validation results using
+//! camelCase field names
+
+use crate::{RawValue, SubmissionSummary};
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Value given as code together with its human-readable label
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CodedValue {
+    /// Code as given within the Meldebestätigung
+    pub code: String,
+    /// Label of the code, or a note if the code is unknown
+    pub display: String,
+}
+
+impl CodedValue {
+    fn new(value: &(impl RawValue + Display)) -> Self {
+        CodedValue {
+            code: value.raw_value().to_string(),
+            display: value.to_string(),
+        }
+    }
+}
+
+/// Parsed content of a Meldebestätigung
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryJson {
+    /// Vorgangsnummer, the TAN of the submission
+    pub tan: String,
+    /// Code of the Leistungserbringer
+    pub code: String,
+    /// Date of the submission formatted as `YYYY-MM-DD`
+    pub date: String,
+    /// Laufende Nummer of the submission on this date
+    pub counter: String,
+    /// Institutionskennzeichen of the Leistungserbringer
+    pub ik: CodedValue,
+    /// Data node the data has been submitted to
+    pub datacenter: CodedValue,
+    /// Type of the submission
+    pub typ_der_meldung: CodedValue,
+    /// Indication area of the submission
+    pub indikationsbereich: CodedValue,
+    /// Type of payer
+    pub kostentraeger: CodedValue,
+    /// Type of data submitted
+    pub art_der_daten: CodedValue,
+    /// Type of sequencing
+    pub art_der_sequenzierung: CodedValue,
+    /// True if the quality control has been passed
+    pub accepted: bool,
+    /// String the hash value is calculated from
+    pub hash_string: String,
+    /// SHA-256 of the hash string as given within the Meldebestätigung
+    pub hash_wert: String,
+}
+
+impl From<&SubmissionSummary> for SummaryJson {
+    fn from(summary: &SubmissionSummary) -> Self {
+        SummaryJson {
+            tan: summary.tan().raw_value().to_string(),
+            code: summary.code().raw_value().to_string(),
+            date: summary.date().raw_value().to_string(),
+            counter: summary.counter().raw_value().to_string(),
+            ik: CodedValue::new(summary.ik()),
+            datacenter: CodedValue::new(summary.datacenter()),
+            typ_der_meldung: CodedValue::new(summary.typ_der_meldung()),
+            indikationsbereich: CodedValue::new(summary.indikationsbereich()),
+            kostentraeger: CodedValue::new(summary.kostentraeger()),
+            art_der_daten: CodedValue::new(summary.art_der_daten()),
+            art_der_sequenzierung: CodedValue::new(summary.art_der_sequenzierung()),
+            accepted: summary.accepted(),
+            hash_string: summary.hash_string().to_string(),
+            hash_wert: summary.hash_wert().raw_value().to_string(),
+        }
+    }
+}
+
+/// Validation result of a single field
+#[derive(Debug, PartialEq, Serialize)]
+pub struct FieldValidation {
+    /// German label of the field as shown to users
+    pub label: String,
+    /// Value as given within the Meldebestätigung
+    pub value: String,
+    /// False if the value has been rejected by validation
+    pub valid: bool,
+}
+
+/// Validation results of a Meldebestätigung
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationReport {
+    /// True if no field is invalid
+    pub valid: bool,
+    /// True if the given hash value matches the SHA-256 of the hash string
+    pub valid_hash: bool,
+    /// True if the quality control has been passed
+    pub accepted: bool,
+    /// Validation results of all fields in order of appearance
+    pub fields: Vec<FieldValidation>,
+}
+
+impl From<&SubmissionSummary> for ValidationReport {
+    fn from(summary: &SubmissionSummary) -> Self {
+        ValidationReport {
+            valid: summary.is_valid(),
+            valid_hash: summary.valid_hash(),
+            accepted: summary.accepted(),
+            fields: summary
+                .fields()
+                .into_iter()
+                .map(|field| FieldValidation {
+                    label: field.label.to_string(),
+                    value: field.raw,
+                    valid: !field.invalid,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Parsed content of a Meldebestätigung together with its validation results
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ParsedSummary {
+    /// Parsed content
+    pub summary: SummaryJson,
+    /// Validation results
+    pub validation: ValidationReport,
+}
+
+impl From<&SubmissionSummary> for ParsedSummary {
+    fn from(summary: &SubmissionSummary) -> Self {
+        ParsedSummary {
+            summary: SummaryJson::from(summary),
+            validation: ValidationReport::from(summary),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const ERSTMELDUNG: &str = "Vorgangsnummer,Meldebestaetigung\nbad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31";
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_serialize_summary() {
+        let summary = SubmissionSummary::from_str(ERSTMELDUNG).unwrap();
+
+        let json = serde_json::to_value(SummaryJson::from(&summary)).unwrap();
+
+        assert_eq!(json["code"], "A123456789");
+        assert_eq!(json["date"], "2024-07-01");
+        assert_eq!(json["ik"]["code"], "260530103");
+        assert_eq!(json["typDerMeldung"]["code"], "0");
+        assert_eq!(json["typDerMeldung"]["display"], "Erstmeldung");
+        assert_eq!(json["accepted"], true);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_report_validation() {
+        let summary = SubmissionSummary::from_str(ERSTMELDUNG).unwrap();
+
+        let report = ValidationReport::from(&summary);
+
+        assert_eq!(report.valid, summary.is_valid());
+        assert!(report.valid_hash);
+        assert_eq!(report.fields.len(), summary.fields().len());
+        assert!(
+            serde_json::to_string(&report)
+                .unwrap()
+                .contains("\"validHash\":true")
+        );
+    }
+}
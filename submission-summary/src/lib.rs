@@ -0,0 +1,31 @@
+//! Parser and validation of MV §64e Meldebestätigungen.
+//!
+//! A Meldebestätigung is read from the content of its CSV file or from a single data line.
+//! Unknown codes do not prevent parsing, they are kept and reported as invalid field instead.
+//!
+//! ```
+//! use submission_summary::{RawValue, SubmissionSummary};
+//! use submission_summary::json::ParsedSummary;
+//!
+//! let summary = SubmissionSummary::from_csv_or_line(
+//!     "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&GRZK00001&0&O&9&1&C&2&1+9+0de4d37ab8ad0ef2b8bd76c8c2e6c1db2ee404c3fe9f4fd4d79d9b5e2b22f9d2",
+//! )?;
+//!
+//! assert_eq!(summary.code().raw_value(), "A123456789");
+//! assert_eq!(summary.kostentraeger().to_string(), "GKV");
+//! assert!(!summary.valid_hash());
+//! assert!(!summary.is_valid());
+//!
+//! let json = serde_json::to_string(&ParsedSummary::from(&summary))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+#![warn(missing_docs)]
+
+pub mod json;
+mod submission_summary;
+
+pub use submission_summary::{
+    ArtDerDaten, ArtDerSequenzierung, CSV_HEADER, CheckedValue, Datacenter, Field, FieldKind, Ik,
+    Indikationsbereich, Kostentraeger, ParseError, RawValue, StringValue, SubmissionSummary,
+    SubmissionSummaryBuilder, TypDerMeldung,
+};
@@ -0,0 +1,1563 @@
+use chrono::NaiveDate;
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Header line of a Meldebestätigung CSV file
+pub const CSV_HEADER: &str = "Vorgangsnummer,Meldebestaetigung";
+
+/// Parsed Meldebestätigung. Created from the content of a file using [`FromStr`] or by
+/// [`SubmissionSummary::from_csv_or_line`]. The values are read-only, so they always match
+/// [`SubmissionSummary::line`] and [`SubmissionSummary::hash_string`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmissionSummary {
+    tan: StringValue,
+    code: StringValue,
+    date: StringValue,
+    counter: StringValue,
+    ik: Ik,
+    datacenter: Datacenter,
+    typ_der_meldung: TypDerMeldung,
+    indikationsbereich: Indikationsbereich,
+    kostentraeger: Kostentraeger,
+    art_der_daten: ArtDerDaten,
+    art_der_sequenzierung: ArtDerSequenzierung,
+    accepted: bool,
+    hash_wert: StringValue,
+    hash_string: String,
+    line: String,
+}
+
+impl SubmissionSummary {
+    /// Vorgangsnummer, the TAN of the submission
+    #[must_use]
+    pub fn tan(&self) -> &StringValue {
+        &self.tan
+    }
+
+    /// Code of the Leistungserbringer
+    #[must_use]
+    pub fn code(&self) -> &StringValue {
+        &self.code
+    }
+
+    /// Date of the submission formatted as `YYYY-MM-DD`
+    #[must_use]
+    pub fn date(&self) -> &StringValue {
+        &self.date
+    }
+
+    /// Laufende Nummer of the submission on this date
+    #[must_use]
+    pub fn counter(&self) -> &StringValue {
+        &self.counter
+    }
+
+    /// Institutionskennzeichen of the Leistungserbringer
+    #[must_use]
+    pub fn ik(&self) -> &Ik {
+        &self.ik
+    }
+
+    /// Data node the data has been submitted to
+    #[must_use]
+    pub fn datacenter(&self) -> &Datacenter {
+        &self.datacenter
+    }
+
+    /// Type of the submission
+    #[must_use]
+    pub fn typ_der_meldung(&self) -> &TypDerMeldung {
+        &self.typ_der_meldung
+    }
+
+    /// Indication area of the submission
+    #[must_use]
+    pub fn indikationsbereich(&self) -> &Indikationsbereich {
+        &self.indikationsbereich
+    }
+
+    /// Type of payer
+    #[must_use]
+    pub fn kostentraeger(&self) -> &Kostentraeger {
+        &self.kostentraeger
+    }
+
+    /// Type of data submitted
+    #[must_use]
+    pub fn art_der_daten(&self) -> &ArtDerDaten {
+        &self.art_der_daten
+    }
+
+    /// Type of sequencing
+    #[must_use]
+    pub fn art_der_sequenzierung(&self) -> &ArtDerSequenzierung {
+        &self.art_der_sequenzierung
+    }
+
+    /// True if the quality control has been passed
+    #[must_use]
+    pub fn accepted(&self) -> bool {
+        self.accepted
+    }
+
+    /// SHA-256 of the hash string as given within the Meldebestätigung
+    #[must_use]
+    pub fn hash_wert(&self) -> &StringValue {
+        &self.hash_wert
+    }
+}
+
+impl SubmissionSummary {
+    /// True if the given hash value matches the SHA-256 of the hash string
+    #[must_use]
+    pub fn valid_hash(&self) -> bool {
+        Self::hash(&self.hash_string) == self.hash_wert.0
+    }
+
+    /// Lowercase hex encoded SHA-256 of the given hash string
+    #[must_use]
+    pub fn hash(hash_string: &str) -> String {
+        Self::file_hash(hash_string.as_bytes())
+    }
+
+    /// Lowercase hex encoded SHA-256 of the given file content
+    #[must_use]
+    pub fn file_hash(content: &[u8]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(content);
+        let hash_result = hasher.finalize();
+        base16ct::lower::encode_string(&hash_result)
+    }
+
+    /// Validation verdict, true if no field is invalid
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.fields().iter().all(|field| !field.invalid)
+    }
+
+    /// String the hash value is calculated from
+    #[must_use]
+    pub fn hash_string(&self) -> &str {
+        &self.hash_string
+    }
+
+    /// The data line of the CSV file as it has been read
+    #[must_use]
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    /// The date, if it is a valid calendar date
+    #[must_use]
+    pub fn parsed_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.date.0, "%Y-%m-%d").ok()
+    }
+
+    /// The counter as number, if it is numeric
+    #[must_use]
+    pub fn parsed_counter(&self) -> Option<u32> {
+        self.counter.0.parse().ok()
+    }
+
+    /// All fields in order of appearance including the hash string
+    #[must_use]
+    pub fn fields(&self) -> Vec<Field> {
+        fn field(
+            label: &'static str,
+            kind: FieldKind,
+            value: &(impl CheckedValue + RawValue),
+        ) -> Field {
+            Field {
+                label,
+                kind,
+                raw: value.raw_value().to_string(),
+                display: value.to_string(),
+                invalid: value.is_invalid(),
+            }
+        }
+
+        vec![
+            field("TAN", FieldKind::Identifying, &self.tan),
+            field("Code", FieldKind::Identifying, &self.code),
+            field("Datum", FieldKind::Plain, &self.date),
+            field("Laufende Nummer", FieldKind::Plain, &self.counter),
+            field("Leistungserbringer", FieldKind::Coded, &self.ik),
+            field("Datenknoten", FieldKind::Coded, &self.datacenter),
+            field("Typ der Meldung", FieldKind::Coded, &self.typ_der_meldung),
+            field(
+                "Indikationsbereich",
+                FieldKind::Coded,
+                &self.indikationsbereich,
+            ),
+            field("Kostenträger", FieldKind::Coded, &self.kostentraeger),
+            field("Art der Daten", FieldKind::Coded, &self.art_der_daten),
+            field(
+                "Art der Sequenzierung",
+                FieldKind::Coded,
+                &self.art_der_sequenzierung,
+            ),
+            Field {
+                label: "Qualitätskontrolle",
+                kind: FieldKind::Coded,
+                raw: if self.accepted { "1" } else { "0" }.to_string(),
+                display: if self.accepted {
+                    "bestanden"
+                } else {
+                    "nicht bestanden"
+                }
+                .to_string(),
+                invalid: !self.accepted,
+            },
+            Field {
+                label: "Hash-String",
+                kind: FieldKind::Identifying,
+                raw: self.hash_string.clone(),
+                display: self.hash_string.clone(),
+                invalid: false,
+            },
+            Field {
+                label: "Sha256-Hash",
+                kind: FieldKind::Identifying,
+                raw: self.hash_wert.0.clone(),
+                display: self.hash_wert.0.clone(),
+                invalid: !self.valid_hash(),
+            },
+        ]
+    }
+}
+
+/// Labeled field of a Meldebestätigung with raw and human-readable value
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    /// German label of the field as shown to users
+    pub label: &'static str,
+    /// Kind of the value
+    pub kind: FieldKind,
+    /// Value as given within the Meldebestätigung
+    pub raw: String,
+    /// Human-readable value, e.g. the label of a code
+    pub display: String,
+    /// True if the value has been rejected by validation
+    pub invalid: bool,
+}
+
+/// Kind of the value of a [`Field`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldKind {
+    /// Identifies the submission or the case, e.g. TAN, code and the hashes computed from them
+    Identifying,
+    /// Uncoded value, e.g. the date
+    Plain,
+    /// Coded value with its label as human-readable value
+    Coded,
+}
+
+/// Builds the data line of a Meldebestätigung from its values. Hash-String and Sha256-Hash are
+/// always computed from the given values.
+///
+/// Starting from a parsed Meldebestätigung keeps all values not set explicitly, including the
+/// values of unknown meaning.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmissionSummaryBuilder {
+    tan: String,
+    code: String,
+    date: String,
+    counter: String,
+    ik: String,
+    datacenter: String,
+    typ_der_meldung: String,
+    indikationsbereich: String,
+    unknown_hash_string_value: String,
+    kostentraeger: String,
+    art_der_daten: String,
+    art_der_sequenzierung: String,
+    accepted: bool,
+    unknown_segment: String,
+}
+
+impl Default for SubmissionSummaryBuilder {
+    fn default() -> Self {
+        Self {
+            tan: String::new(),
+            code: String::new(),
+            date: String::new(),
+            counter: "001".to_string(),
+            ik: String::new(),
+            datacenter: String::new(),
+            typ_der_meldung: TypDerMeldung::Erstmeldung.raw_value().to_string(),
+            indikationsbereich: String::new(),
+            unknown_hash_string_value: "9".to_string(),
+            kostentraeger: String::new(),
+            art_der_daten: String::new(),
+            art_der_sequenzierung: ArtDerSequenzierung::Keine.raw_value().to_string(),
+            accepted: true,
+            unknown_segment: "9".to_string(),
+        }
+    }
+}
+
+impl From<&SubmissionSummary> for SubmissionSummaryBuilder {
+    fn from(summary: &SubmissionSummary) -> Self {
+        // Both have been checked for their number of parts while parsing
+        let hash_string = summary.hash_string.split('&').collect::<Vec<_>>();
+        let segments = summary.line.split('+').collect::<Vec<_>>();
+        Self {
+            tan: summary.tan.0.clone(),
+            code: summary.code.0.clone(),
+            date: summary.date.0.clone(),
+            counter: summary.counter.0.clone(),
+            ik: summary.ik.raw_value().to_string(),
+            datacenter: summary.datacenter.raw_value().to_string(),
+            typ_der_meldung: summary.typ_der_meldung.raw_value().to_string(),
+            indikationsbereich: summary.indikationsbereich.raw_value().to_string(),
+            unknown_hash_string_value: hash_string.get(6).unwrap_or(&"").to_string(),
+            kostentraeger: summary.kostentraeger.raw_value().to_string(),
+            art_der_daten: summary.art_der_daten.raw_value().to_string(),
+            art_der_sequenzierung: summary.art_der_sequenzierung.raw_value().to_string(),
+            accepted: summary.accepted,
+            unknown_segment: segments.get(3).unwrap_or(&"").to_string(),
+        }
+    }
+}
+
+impl SubmissionSummaryBuilder {
+    /// Sets the Vorgangsnummer, the TAN of the submission
+    #[must_use]
+    pub fn tan(mut self, tan: &str) -> Self {
+        self.tan = tan.to_string();
+        self
+    }
+
+    /// Sets the code of the Leistungserbringer
+    #[must_use]
+    pub fn code(mut self, code: &str) -> Self {
+        self.code = code.to_string();
+        self
+    }
+
+    /// Sets the date formatted as `YYYY-MM-DD`
+    #[must_use]
+    pub fn date(mut self, date: &str) -> Self {
+        self.date = date.to_string();
+        self
+    }
+
+    /// Sets the laufende Nummer of the day, e.g. `001`
+    #[must_use]
+    pub fn counter(mut self, counter: &str) -> Self {
+        self.counter = counter.to_string();
+        self
+    }
+
+    /// Sets the Leistungserbringer
+    #[must_use]
+    pub fn ik(mut self, ik: &Ik) -> Self {
+        self.ik = ik.raw_value().to_string();
+        self
+    }
+
+    /// Sets the Datenknoten
+    #[must_use]
+    pub fn datacenter(mut self, datacenter: &Datacenter) -> Self {
+        self.datacenter = datacenter.raw_value().to_string();
+        self
+    }
+
+    /// Sets the Typ der Meldung
+    #[must_use]
+    pub fn typ_der_meldung(mut self, typ_der_meldung: &TypDerMeldung) -> Self {
+        self.typ_der_meldung = typ_der_meldung.raw_value().to_string();
+        self
+    }
+
+    /// Sets the Indikationsbereich
+    #[must_use]
+    pub fn indikationsbereich(mut self, indikationsbereich: &Indikationsbereich) -> Self {
+        self.indikationsbereich = indikationsbereich.raw_value().to_string();
+        self
+    }
+
+    /// Sets the Kostenträger
+    #[must_use]
+    pub fn kostentraeger(mut self, kostentraeger: &Kostentraeger) -> Self {
+        self.kostentraeger = kostentraeger.raw_value().to_string();
+        self
+    }
+
+    /// Sets the Art der Daten
+    #[must_use]
+    pub fn art_der_daten(mut self, art_der_daten: &ArtDerDaten) -> Self {
+        self.art_der_daten = art_der_daten.raw_value().to_string();
+        self
+    }
+
+    /// Sets the Art der Sequenzierung
+    #[must_use]
+    pub fn art_der_sequenzierung(mut self, art_der_sequenzierung: &ArtDerSequenzierung) -> Self {
+        self.art_der_sequenzierung = art_der_sequenzierung.raw_value().to_string();
+        self
+    }
+
+    /// Sets whether the Qualitätskontrolle has been passed
+    #[must_use]
+    pub fn accepted(mut self, accepted: bool) -> Self {
+        self.accepted = accepted;
+        self
+    }
+
+    /// The '&' separated part of the Meldebestätigung the hash value is computed from
+    #[must_use]
+    pub fn hash_string(&self) -> String {
+        [
+            self.code.as_str(),
+            &format!("{}{}", self.date.replace('-', ""), self.counter),
+            &self.ik,
+            &self.datacenter,
+            &self.typ_der_meldung,
+            &self.indikationsbereich,
+            &self.unknown_hash_string_value,
+            &self.kostentraeger,
+            &self.art_der_daten,
+            &self.art_der_sequenzierung,
+            if self.accepted { "1" } else { "0" },
+        ]
+        .join("&")
+    }
+
+    /// The data line as it appears within the CSV file
+    #[must_use]
+    pub fn line(&self) -> String {
+        let hash_string = self.hash_string();
+        format!(
+            "{},IBE+{}+{}+{}+{}",
+            self.tan,
+            self.code,
+            hash_string,
+            self.unknown_segment,
+            SubmissionSummary::hash(&hash_string)
+        )
+    }
+
+    /// Parses the built line the same way a loaded file is parsed
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ParseError`] if the given values do not result in a valid structure,
+    /// e.g. if the date is missing.
+    pub fn build(&self) -> Result<SubmissionSummary, ParseError> {
+        SubmissionSummary::from_csv_or_line(&self.line())
+    }
+}
+
+impl SubmissionSummary {
+    #[allow(clippy::expect_used)]
+    fn matches_hash_tan_pattern(s: &str) -> bool {
+        let regexp = regex::Regex::new(r"[0-9a-fA-F]{64}").expect("Invalid regexp");
+        regexp.is_match(s)
+    }
+
+    #[allow(clippy::expect_used)]
+    fn matches_count_pattern(s: &str) -> bool {
+        let regexp = regex::Regex::new(r"^[0-9]{3}$").expect("Invalid regexp");
+        regexp.is_match(s)
+    }
+
+    #[allow(clippy::expect_used)]
+    fn is_reasonable_date(s: &str) -> bool {
+        let regexp = regex::Regex::new(r"^20[0-9]{2}-(0[1-9]|1[0-2])-([0-2][0-9]|3[0-1])$")
+            .expect("Invalid regexp");
+        regexp.is_match(s)
+    }
+
+    /// Parses the content of a Meldebestätigung file or a single line without CSV header
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ParseError`] if the structure of the Meldebestätigung is invalid.
+    pub fn from_csv_or_line(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        if s.starts_with(CSV_HEADER) {
+            Self::from_str(s)
+        } else {
+            Self::from_str(&format!("{CSV_HEADER}\n{s}"))
+        }
+    }
+
+    /// Coded fields take unknown values as such, so the field itself is never rejected
+    fn parse_field<T: FromStr<Err = Infallible>>(s: &str) -> T {
+        let Ok(value) = s.parse();
+        value
+    }
+
+    #[allow(clippy::expect_used)]
+    fn parse_date_and_number(s: &str) -> Option<(String, String)> {
+        let regexp =
+            regex::Regex::new(r"^20[0-9]{2}(0[1-9]|1[0-2])([0-2][0-9]|3[0-1])[0-9]{0,2}[1-9]$")
+                .expect("Invalid regexp");
+
+        if s.len() < 9 || !regexp.is_match(s) {
+            return None;
+        }
+
+        let date = format!("{}-{}-{}", &s[0..4], &s[4..6], &s[6..8]);
+        let counter = s[8..s.len()].parse::<String>().ok();
+
+        counter.map(|counter| (date, counter))
+    }
+}
+
+/// Reason why a Meldebestätigung could not be parsed
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The first line is not the expected CSV header
+    MissingHeader,
+    /// The second line does not consist of Vorgangsnummer and Meldebestätigung
+    InvalidLine,
+    /// The Meldebestätigung does not consist of five parts starting with IBE
+    InvalidMeldebestaetigung,
+    /// The Hash-String does not consist of eleven fields
+    InvalidHashString,
+    /// Date and counter are not given as expected
+    InvalidDate,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::MissingHeader => write!(f, "Kopfzeile fehlt oder ist ungültig"),
+            ParseError::InvalidLine => write!(
+                f,
+                "Zeile besteht nicht aus Vorgangsnummer und Meldebestätigung"
+            ),
+            ParseError::InvalidMeldebestaetigung => {
+                write!(
+                    f,
+                    "Meldebestätigung besteht nicht aus fünf Teilen beginnend mit IBE"
+                )
+            }
+            ParseError::InvalidHashString => {
+                write!(f, "Hash-String besteht nicht aus elf Feldern")
+            }
+            ParseError::InvalidDate => write!(f, "Datum und laufende Nummer sind ungültig"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for SubmissionSummary {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.lines().collect::<Vec<&str>>();
+        if parts.len() < 2 || parts[0] != CSV_HEADER {
+            return Err(ParseError::MissingHeader);
+        }
+
+        let line = parts[1].trim().to_string();
+        let parts = line.split(',').collect::<Vec<&str>>();
+        if parts.len() != 2 {
+            return Err(ParseError::InvalidLine);
+        }
+
+        let tan = parts[0].to_string();
+
+        let parts = parts[1].split('+').collect::<Vec<&str>>();
+        if parts.len() != 5 || parts[0] != "IBE" {
+            return Err(ParseError::InvalidMeldebestaetigung);
+        }
+        let hash_string = parts[2].to_string();
+        let hash_wert = parts[4].to_string();
+
+        let parts = parts[2].split('&').collect::<Vec<&str>>();
+        if parts.len() != 11 {
+            return Err(ParseError::InvalidHashString);
+        }
+
+        let Some((date, counter)) = Self::parse_date_and_number(parts[1]) else {
+            return Err(ParseError::InvalidDate);
+        };
+
+        Ok(SubmissionSummary {
+            tan: StringValue::new(&tan, !Self::matches_hash_tan_pattern(&tan)),
+            code: StringValue::new_valid(parts[0]),
+            date: StringValue::new(&date, !Self::is_reasonable_date(&date)),
+            counter: StringValue::new(&counter, !Self::matches_count_pattern(&counter)),
+            ik: Self::parse_field(parts[2]),
+            datacenter: Self::parse_field(parts[3]),
+            typ_der_meldung: Self::parse_field(parts[4]),
+            indikationsbereich: Self::parse_field(parts[5]),
+            kostentraeger: Self::parse_field(parts[7]),
+            art_der_daten: Self::parse_field(parts[8]),
+            art_der_sequenzierung: Self::parse_field(parts[9]),
+            accepted: parts[10] == "1",
+            hash_string,
+            line: line.clone(),
+            hash_wert: StringValue::new(&hash_wert, !Self::matches_hash_tan_pattern(&hash_wert)),
+        })
+    }
+}
+
+/// Validation of a single value
+pub trait CheckedValue
+where
+    Self: Display + Sized,
+{
+    /// True if the value is empty, has an invalid format or is no known code
+    fn is_invalid(&self) -> bool;
+}
+
+/// Access to the raw value as used within the Meldebestätigung
+pub trait RawValue {
+    /// The value as given, e.g. the code of a coded value
+    fn raw_value(&self) -> &str;
+}
+
+/// Uncoded value and whether it has been rejected by validation. Use [`RawValue`] and
+/// [`CheckedValue`] to access both.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StringValue(String, bool);
+
+impl StringValue {
+    /// Value marked as invalid if `invalid` is set. Empty values are always invalid.
+    #[must_use]
+    pub fn new(s: &str, invalid: bool) -> Self {
+        Self(s.to_string(), invalid)
+    }
+
+    /// Value not rejected by validation
+    #[must_use]
+    pub fn new_valid(s: &str) -> Self {
+        Self::new(s, false)
+    }
+
+    /// Value rejected by validation
+    #[must_use]
+    pub fn new_invalid(s: &str) -> Self {
+        Self::new(s, true)
+    }
+}
+
+impl CheckedValue for StringValue {
+    fn is_invalid(&self) -> bool {
+        self.1 || self.0.is_empty()
+    }
+}
+
+impl RawValue for StringValue {
+    fn raw_value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for StringValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Genomrechenzentrum or klinischer Datenknoten the data has been submitted to. Codes not defined by the specification are kept as `Unknown` and are invalid.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Datacenter {
+    /// `GRZK00001`: GRZ Köln
+    GRZK00001,
+    /// `GRZTUE002`: GRZ Tübingen
+    GRZTUE002,
+    /// `GRZHD0003`: GRZ Heidelberg
+    GRZHD0003,
+    /// `GRZDD0004`: GRZ Dresden
+    GRZDD0004,
+    /// `GRZM00006`: GRZ München
+    GRZM00006,
+    /// `GRZB00007`: GRZ Berlin
+    GRZB00007,
+    /// `KDKDD0001`: Gfh-NET
+    KDKDD0001,
+    /// `KDKTUE002`: NSE
+    KDKTUE002,
+    /// `KDKL00003`: DK-FBREK
+    KDKL00003,
+    /// `KDKL00004`: DK-FDK
+    KDKL00004,
+    /// `KDKTUE005`: DNPM
+    KDKTUE005,
+    /// `KDKHD0006`: NCT/DKTK MASTER
+    KDKHD0006,
+    /// `KDKK00007`: nNGM
+    KDKK00007,
+    /// Code not defined by the specification
+    Unknown(String),
+}
+
+impl CheckedValue for Datacenter {
+    fn is_invalid(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl FromStr for Datacenter {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "GRZK00001" => Ok(Datacenter::GRZK00001),
+            "GRZTUE002" => Ok(Datacenter::GRZTUE002),
+            "GRZHD0003" => Ok(Datacenter::GRZHD0003),
+            "GRZDD0004" => Ok(Datacenter::GRZDD0004),
+            "GRZM00006" => Ok(Datacenter::GRZM00006),
+            "GRZB00007" => Ok(Datacenter::GRZB00007),
+            "KDKDD0001" => Ok(Datacenter::KDKDD0001),
+            "KDKTUE002" => Ok(Datacenter::KDKTUE002),
+            "KDKL00003" => Ok(Datacenter::KDKL00003),
+            "KDKL00004" => Ok(Datacenter::KDKL00004),
+            "KDKTUE005" => Ok(Datacenter::KDKTUE005),
+            "KDKHD0006" => Ok(Datacenter::KDKHD0006),
+            "KDKK00007" => Ok(Datacenter::KDKK00007),
+            u => Ok(Datacenter::Unknown(u.to_string())),
+        }
+    }
+}
+
+impl Datacenter {
+    /// All known values
+    #[must_use]
+    pub fn values() -> Vec<Self> {
+        vec![
+            Datacenter::GRZK00001,
+            Datacenter::GRZTUE002,
+            Datacenter::GRZHD0003,
+            Datacenter::GRZDD0004,
+            Datacenter::GRZM00006,
+            Datacenter::GRZB00007,
+            Datacenter::KDKDD0001,
+            Datacenter::KDKTUE002,
+            Datacenter::KDKL00003,
+            Datacenter::KDKL00004,
+            Datacenter::KDKTUE005,
+            Datacenter::KDKHD0006,
+            Datacenter::KDKK00007,
+        ]
+    }
+}
+
+impl RawValue for Datacenter {
+    fn raw_value(&self) -> &str {
+        match self {
+            Datacenter::GRZK00001 => "GRZK00001",
+            Datacenter::GRZTUE002 => "GRZTUE002",
+            Datacenter::GRZHD0003 => "GRZHD0003",
+            Datacenter::GRZDD0004 => "GRZDD0004",
+            Datacenter::GRZM00006 => "GRZM00006",
+            Datacenter::GRZB00007 => "GRZB00007",
+            Datacenter::KDKDD0001 => "KDKDD0001",
+            Datacenter::KDKTUE002 => "KDKTUE002",
+            Datacenter::KDKL00003 => "KDKL00003",
+            Datacenter::KDKL00004 => "KDKL00004",
+            Datacenter::KDKTUE005 => "KDKTUE005",
+            Datacenter::KDKHD0006 => "KDKHD0006",
+            Datacenter::KDKK00007 => "KDKK00007",
+            Datacenter::Unknown(u) => u,
+        }
+    }
+}
+
+impl Display for Datacenter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Datacenter::GRZK00001 => write!(f, "GRZ Köln (GRZK00001)"),
+            Datacenter::GRZTUE002 => write!(f, "GRZ Tübingen (GRZTUE002)"),
+            Datacenter::GRZHD0003 => write!(f, "GRZ Heidelberg (GRZHD0003)"),
+            Datacenter::GRZDD0004 => write!(f, "GRZ Dresden (GRZDD0004)"),
+            Datacenter::GRZM00006 => write!(f, "GRZ München (GRZM00006)"),
+            Datacenter::GRZB00007 => write!(f, "GRZ Berlin (GRZB00007)"),
+            Datacenter::KDKDD0001 => write!(f, "Gfh-NET (KDKDD0001)"),
+            Datacenter::KDKTUE002 => write!(f, "NSE (KDKTUE002)"),
+            Datacenter::KDKL00003 => write!(f, "DK-FBREK (KDKL00003)"),
+            Datacenter::KDKL00004 => write!(f, "DK-FDK (KDKL00004)"),
+            Datacenter::KDKTUE005 => write!(f, "DNPM (KDKTUE005)"),
+            Datacenter::KDKHD0006 => write!(f, "NCT/DKTK MASTER (KDKHD0006)"),
+            Datacenter::KDKK00007 => write!(f, "nNGM (KDKK00007)"),
+            Datacenter::Unknown(u) => write!(f, "Unbekannter Wert: '{u}'"),
+        }
+    }
+}
+
+/// Institutionskennzeichen of the Leistungserbringer. Codes not defined by the specification are kept as `Unknown` and are invalid.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Ik {
+    /// `260530012`: Universitätsklinikum Aachen
+    Ik260530012,
+    /// `261101015`: Charité Universitätsmedizin Berlin
+    Ik261101015,
+    /// `260590071`: Universitätsklinikum der Ruhr-Universität Bochum
+    Ik260590071,
+    /// `260530103`: Universitätsklinikum Bonn
+    Ik260530103,
+    /// `261401030`: Universitätsklinikum Carl Gustav Carus an der TU Dresden
+    Ik261401030,
+    /// `260510018`: Universitätsklinikum Düsseldorf
+    Ik260510018,
+    /// `260950567`: Universitätsklinikum Erlangen
+    Ik260950567,
+    /// `260510381`: Universitätsklinikum Essen
+    Ik260510381,
+    /// `260832299`: Universitätsklinikum Freiburg
+    Ik260832299,
+    /// `260610279`: Universitätsklinikum Gießen und Marburg, Standort Gießen
+    Ik260610279,
+    /// `260310378`: Universitätsmedizin Göttingen
+    Ik260310378,
+    /// `261500702`: Universitätsklinikum Halle
+    Ik261500702,
+    /// `260200013`: Universitätsklinikum Hamburg-Eppendorf
+    Ik260200013,
+    /// `260320597`: Medizinische Hochschule Hannover
+    Ik260320597,
+    /// `260820466`: Universitätsklinikum Heidelberg
+    Ik260820466,
+    /// `261600736`: Universitätsklinikum Jena
+    Ik261600736,
+    /// `260530283`: Universitätsklinikum Köln
+    Ik260530283,
+    /// `261401052`: Universitätsklinikum Leipzig
+    Ik261401052,
+    /// `260730161`: Universitätsmedizin Mainz
+    Ik260730161,
+    /// `260620431`: Universitätsklinikum Gießen und Marburg, Standort Marburg
+    Ik260620431,
+    /// `260914050`: Klinikum der Universität München
+    Ik260914050,
+    /// `260913195`: Klinikum rechts der Isar der TU München/TUM-Klinikum
+    Ik260913195,
+    /// `260550131`: Universitätsklinikum Münster
+    Ik260550131,
+    /// `260930608`: Universitätsklinikum Regensburg
+    Ik260930608,
+    /// `260102343`: Universitätsklinikum Schleswig-Holstein
+    Ik260102343,
+    /// `260840108`: Universitätsklinikum Tübingen
+    Ik260840108,
+    /// `260840200`: Universitätsklinikum Ulm
+    Ik260840200,
+    /// `260960079`: Universitätsklinikum Würzburg
+    Ik260960079,
+    /// Code not defined by the specification
+    Unknown(String),
+}
+
+impl CheckedValue for Ik {
+    fn is_invalid(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl FromStr for Ik {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "260530012" => Ok(Ik::Ik260530012),
+            "261101015" => Ok(Ik::Ik261101015),
+            "260590071" => Ok(Ik::Ik260590071),
+            "260530103" => Ok(Ik::Ik260530103),
+            "261401030" => Ok(Ik::Ik261401030),
+            "260510018" => Ok(Ik::Ik260510018),
+            "260950567" => Ok(Ik::Ik260950567),
+            "260510381" => Ok(Ik::Ik260510381),
+            "260832299" => Ok(Ik::Ik260832299),
+            "260610279" => Ok(Ik::Ik260610279),
+            "260310378" => Ok(Ik::Ik260310378),
+            "261500702" => Ok(Ik::Ik261500702),
+            "260200013" => Ok(Ik::Ik260200013),
+            "260320597" => Ok(Ik::Ik260320597),
+            "260820466" => Ok(Ik::Ik260820466),
+            "261600736" => Ok(Ik::Ik261600736),
+            "260530283" => Ok(Ik::Ik260530283),
+            "261401052" => Ok(Ik::Ik261401052),
+            "260730161" => Ok(Ik::Ik260730161),
+            "260620431" => Ok(Ik::Ik260620431),
+            "260914050" => Ok(Ik::Ik260914050),
+            "260913195" => Ok(Ik::Ik260913195),
+            "260550131" => Ok(Ik::Ik260550131),
+            "260930608" => Ok(Ik::Ik260930608),
+            "260102343" => Ok(Ik::Ik260102343),
+            "260840108" => Ok(Ik::Ik260840108),
+            "260840200" => Ok(Ik::Ik260840200),
+            "260960079" => Ok(Ik::Ik260960079),
+            u => Ok(Ik::Unknown(u.to_string())),
+        }
+    }
+}
+
+impl Ik {
+    /// All known values
+    #[must_use]
+    pub fn values() -> Vec<Self> {
+        vec![
+            Ik::Ik260530012,
+            Ik::Ik261101015,
+            Ik::Ik260590071,
+            Ik::Ik260530103,
+            Ik::Ik261401030,
+            Ik::Ik260510018,
+            Ik::Ik260950567,
+            Ik::Ik260510381,
+            Ik::Ik260832299,
+            Ik::Ik260610279,
+            Ik::Ik260310378,
+            Ik::Ik261500702,
+            Ik::Ik260200013,
+            Ik::Ik260320597,
+            Ik::Ik260820466,
+            Ik::Ik261600736,
+            Ik::Ik260530283,
+            Ik::Ik261401052,
+            Ik::Ik260730161,
+            Ik::Ik260620431,
+            Ik::Ik260914050,
+            Ik::Ik260913195,
+            Ik::Ik260550131,
+            Ik::Ik260930608,
+            Ik::Ik260102343,
+            Ik::Ik260840108,
+            Ik::Ik260840200,
+            Ik::Ik260960079,
+        ]
+    }
+}
+
+impl RawValue for Ik {
+    fn raw_value(&self) -> &str {
+        match self {
+            Ik::Ik260530012 => "260530012",
+            Ik::Ik261101015 => "261101015",
+            Ik::Ik260590071 => "260590071",
+            Ik::Ik260530103 => "260530103",
+            Ik::Ik261401030 => "261401030",
+            Ik::Ik260510018 => "260510018",
+            Ik::Ik260950567 => "260950567",
+            Ik::Ik260510381 => "260510381",
+            Ik::Ik260832299 => "260832299",
+            Ik::Ik260610279 => "260610279",
+            Ik::Ik260310378 => "260310378",
+            Ik::Ik261500702 => "261500702",
+            Ik::Ik260200013 => "260200013",
+            Ik::Ik260320597 => "260320597",
+            Ik::Ik260820466 => "260820466",
+            Ik::Ik261600736 => "261600736",
+            Ik::Ik260530283 => "260530283",
+            Ik::Ik261401052 => "261401052",
+            Ik::Ik260730161 => "260730161",
+            Ik::Ik260620431 => "260620431",
+            Ik::Ik260914050 => "260914050",
+            Ik::Ik260913195 => "260913195",
+            Ik::Ik260550131 => "260550131",
+            Ik::Ik260930608 => "260930608",
+            Ik::Ik260102343 => "260102343",
+            Ik::Ik260840108 => "260840108",
+            Ik::Ik260840200 => "260840200",
+            Ik::Ik260960079 => "260960079",
+            Ik::Unknown(u) => u,
+        }
+    }
+}
+
+impl Display for Ik {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Ik::Ik260530012 => write!(f, "Universitätsklinikum Aachen (260530012)"),
+            Ik::Ik261101015 => write!(f, "Charité Universitätsmedizin Berlin (261101015)"),
+            Ik::Ik260590071 => write!(
+                f,
+                "Universitätsklinikum der Ruhr-Universität Bochum (260590071)"
+            ),
+            Ik::Ik261401030 => write!(
+                f,
+                "Universitätsklinikum Carl Gustav Carus an der TU Dresden (261401030)"
+            ),
+            Ik::Ik260530103 => write!(f, "Universitätsklinikum Bonn (260530103)"),
+            Ik::Ik260510018 => write!(f, "Universitätsklinikum Düsseldorf (260510018)"),
+            Ik::Ik260950567 => write!(f, "Universitätsklinikum Erlangen (260950567)"),
+            Ik::Ik260510381 => write!(f, "Universitätsklinikum Essen (260510381)"),
+            Ik::Ik260832299 => write!(f, "Universitätsklinikum Freiburg (260832299)"),
+            Ik::Ik260610279 => write!(
+                f,
+                "Universitätsklinikum Gießen und Marburg, Standort Gießen (260610279)"
+            ),
+            Ik::Ik260310378 => write!(f, "Universitätsmedizin Göttingen (260310378)"),
+            Ik::Ik261500702 => write!(f, "Universitätsklinikum Halle (261500702)"),
+            Ik::Ik260200013 => write!(f, "Universitätsklinikum Hamburg-Eppendorf (260200013)"),
+            Ik::Ik260320597 => write!(f, "Medizinische Hochschule Hannover (260320597)"),
+            Ik::Ik260820466 => write!(f, "Universitätsklinikum Heidelberg (260820466)"),
+            Ik::Ik261600736 => write!(f, "Universitätsklinikum Jena (261600736)"),
+            Ik::Ik260530283 => write!(f, "Universitätsklinikum Köln (260530283)"),
+            Ik::Ik261401052 => write!(f, "Universitätsklinikum Leipzig (261401052)"),
+            Ik::Ik260730161 => write!(f, "Universitätsmedizin Mainz (260730161)"),
+            Ik::Ik260620431 => write!(
+                f,
+                "Universitätsklinikum Gießen und Marburg, Standort Marburg (260620431)"
+            ),
+            Ik::Ik260914050 => write!(f, "Klinikum der Universität München (260914050)"),
+            Ik::Ik260913195 => write!(
+                f,
+                "Klinikum rechts der Isar der TU München/TUM-Klinikum (260913195)"
+            ),
+            Ik::Ik260550131 => write!(f, "Universitätsklinikum Münster (260550131)"),
+            Ik::Ik260930608 => write!(f, "Universitätsklinikum Regensburg (260930608)"),
+            Ik::Ik260102343 => write!(f, "Universitätsklinikum Schleswig-Holstein (260102343)"),
+            Ik::Ik260840108 => write!(f, "Universitätsklinikum Tübingen (260840108)"),
+            Ik::Ik260840200 => write!(f, "Universitätsklinikum Ulm (260840200)"),
+            Ik::Ik260960079 => write!(f, "Universitätsklinikum Würzburg (260960079)"),
+            Ik::Unknown(u) => write!(f, "Unbekannter Wert: '{u}'"),
+        }
+    }
+}
+
+/// Type of the submission. Codes not defined by the specification are kept as `Unknown` and are invalid.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum TypDerMeldung {
+    /// `0`: Erstmeldung
+    Erstmeldung,
+    /// `1`: Follow-Up
+    FollowUp,
+    /// `2`: Nachmeldung
+    Nachmeldung,
+    /// `3`: Korrektur
+    Korrektur,
+    /// `9`: Testmeldung
+    Testmeldung,
+    /// Code not defined by the specification
+    Unknown(String),
+}
+
+impl CheckedValue for TypDerMeldung {
+    fn is_invalid(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl FromStr for TypDerMeldung {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(TypDerMeldung::Erstmeldung),
+            "1" => Ok(TypDerMeldung::FollowUp),
+            "2" => Ok(TypDerMeldung::Nachmeldung),
+            "3" => Ok(TypDerMeldung::Korrektur),
+            "9" => Ok(TypDerMeldung::Testmeldung),
+            u => Ok(TypDerMeldung::Unknown(u.to_string())),
+        }
+    }
+}
+
+impl TypDerMeldung {
+    /// All known values
+    #[must_use]
+    pub fn values() -> Vec<Self> {
+        vec![
+            TypDerMeldung::Erstmeldung,
+            TypDerMeldung::FollowUp,
+            TypDerMeldung::Nachmeldung,
+            TypDerMeldung::Korrektur,
+            TypDerMeldung::Testmeldung,
+        ]
+    }
+}
+
+impl RawValue for TypDerMeldung {
+    fn raw_value(&self) -> &str {
+        match self {
+            TypDerMeldung::Erstmeldung => "0",
+            TypDerMeldung::FollowUp => "1",
+            TypDerMeldung::Nachmeldung => "2",
+            TypDerMeldung::Korrektur => "3",
+            TypDerMeldung::Testmeldung => "9",
+            TypDerMeldung::Unknown(u) => u,
+        }
+    }
+}
+
+impl Display for TypDerMeldung {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypDerMeldung::Erstmeldung => write!(f, "Erstmeldung"),
+            TypDerMeldung::FollowUp => write!(f, "Follow-Up"),
+            TypDerMeldung::Nachmeldung => write!(f, "Nachmeldung"),
+            TypDerMeldung::Korrektur => write!(f, "Korrektur"),
+            TypDerMeldung::Testmeldung => write!(f, "Testmeldung"),
+            TypDerMeldung::Unknown(u) => write!(f, "Unbekannter Wert: '{u}'"),
+        }
+    }
+}
+
+/// Indication area of the submission. Codes not defined by the specification are kept as `Unknown` and are invalid.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Indikationsbereich {
+    /// `O`: Onkologische Erkrankung
+    O,
+    /// `R`: Seltene Erkrankung
+    R,
+    /// `H`: Hereditäres Tumorprädispositionssyndrom
+    H,
+    /// Code not defined by the specification
+    Unknown(String),
+}
+
+impl CheckedValue for Indikationsbereich {
+    fn is_invalid(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl FromStr for Indikationsbereich {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "O" => Ok(Indikationsbereich::O),
+            "R" => Ok(Indikationsbereich::R),
+            "H" => Ok(Indikationsbereich::H),
+            u => Ok(Indikationsbereich::Unknown(u.to_string())),
+        }
+    }
+}
+
+impl Indikationsbereich {
+    /// All known values
+    #[must_use]
+    pub fn values() -> Vec<Self> {
+        vec![
+            Indikationsbereich::O,
+            Indikationsbereich::R,
+            Indikationsbereich::H,
+        ]
+    }
+}
+
+impl RawValue for Indikationsbereich {
+    fn raw_value(&self) -> &str {
+        match self {
+            Indikationsbereich::O => "O",
+            Indikationsbereich::R => "R",
+            Indikationsbereich::H => "H",
+            Indikationsbereich::Unknown(u) => u,
+        }
+    }
+}
+
+impl Display for Indikationsbereich {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Indikationsbereich::O => write!(f, "Onkologische Erkrankung"),
+            Indikationsbereich::R => write!(f, "Seltene Erkrankung"),
+            Indikationsbereich::H => write!(f, "Hereditäres Tumorprädispositionssyndrom"),
+            Indikationsbereich::Unknown(u) => write!(f, "Unbekannter Wert: '{u}'"),
+        }
+    }
+}
+
+/// Type of payer. Codes not defined by the specification are kept as `Unknown` and are invalid.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Kostentraeger {
+    /// `1`: GKV
+    Gkv,
+    /// `2`: PKV
+    Pkv,
+    /// `3`: PKV/Beihilfe
+    PkvBeihilfe,
+    /// `4`: andere
+    Andere,
+    /// Code not defined by the specification
+    Unknown(String),
+}
+
+impl CheckedValue for Kostentraeger {
+    fn is_invalid(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl FromStr for Kostentraeger {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Kostentraeger::Gkv),
+            "2" => Ok(Kostentraeger::Pkv),
+            "3" => Ok(Kostentraeger::PkvBeihilfe),
+            "4" => Ok(Kostentraeger::Andere),
+            u => Ok(Kostentraeger::Unknown(u.to_string())),
+        }
+    }
+}
+
+impl Kostentraeger {
+    /// All known values
+    #[must_use]
+    pub fn values() -> Vec<Self> {
+        vec![
+            Kostentraeger::Gkv,
+            Kostentraeger::Pkv,
+            Kostentraeger::PkvBeihilfe,
+            Kostentraeger::Andere,
+        ]
+    }
+}
+
+impl RawValue for Kostentraeger {
+    fn raw_value(&self) -> &str {
+        match self {
+            Kostentraeger::Gkv => "1",
+            Kostentraeger::Pkv => "2",
+            Kostentraeger::PkvBeihilfe => "3",
+            Kostentraeger::Andere => "4",
+            Kostentraeger::Unknown(u) => u,
+        }
+    }
+}
+
+impl Display for Kostentraeger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kostentraeger::Gkv => write!(f, "GKV"),
+            Kostentraeger::Pkv => write!(f, "PKV"),
+            Kostentraeger::PkvBeihilfe => write!(f, "PKV/Beihilfe"),
+            Kostentraeger::Andere => write!(f, "andere"),
+            Kostentraeger::Unknown(u) => write!(f, "Unbekannter Wert: '{u}'"),
+        }
+    }
+}
+
+/// Type of data submitted. Codes not defined by the specification are kept as `Unknown` and are invalid.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ArtDerDaten {
+    /// `C`: Klinische Daten
+    C,
+    /// `G`: genomische Daten
+    G,
+    /// Code not defined by the specification
+    Unknown(String),
+}
+
+impl CheckedValue for ArtDerDaten {
+    fn is_invalid(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl FromStr for ArtDerDaten {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "C" => Ok(ArtDerDaten::C),
+            "G" => Ok(ArtDerDaten::G),
+            u => Ok(ArtDerDaten::Unknown(u.to_string())),
+        }
+    }
+}
+
+impl ArtDerDaten {
+    /// All known values
+    #[must_use]
+    pub fn values() -> Vec<Self> {
+        vec![ArtDerDaten::C, ArtDerDaten::G]
+    }
+}
+
+impl RawValue for ArtDerDaten {
+    fn raw_value(&self) -> &str {
+        match self {
+            ArtDerDaten::C => "C",
+            ArtDerDaten::G => "G",
+            ArtDerDaten::Unknown(u) => u,
+        }
+    }
+}
+
+impl Display for ArtDerDaten {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArtDerDaten::C => write!(f, "Klinische Daten"),
+            ArtDerDaten::G => write!(f, "genomische Daten"),
+            ArtDerDaten::Unknown(u) => write!(f, "Unbekannter Wert: '{u}'"),
+        }
+    }
+}
+
+/// Type of sequencing. Codes not defined by the specification are kept as `Unknown` and are invalid.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ArtDerSequenzierung {
+    /// `0`: Keine
+    Keine,
+    /// `1`: WGS
+    Wgs,
+    /// `2`: WES
+    Wes,
+    /// `3`: Panel
+    Panel,
+    /// `4`: WGS/LR
+    WgsLr,
+    /// Code not defined by the specification
+    Unknown(String),
+}
+
+impl CheckedValue for ArtDerSequenzierung {
+    fn is_invalid(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl FromStr for ArtDerSequenzierung {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(ArtDerSequenzierung::Keine),
+            "1" => Ok(ArtDerSequenzierung::Wgs),
+            "2" => Ok(ArtDerSequenzierung::Wes),
+            "3" => Ok(ArtDerSequenzierung::Panel),
+            "4" => Ok(ArtDerSequenzierung::WgsLr),
+            u => Ok(ArtDerSequenzierung::Unknown(u.to_string())),
+        }
+    }
+}
+
+impl ArtDerSequenzierung {
+    /// All known values
+    #[must_use]
+    pub fn values() -> Vec<Self> {
+        vec![
+            ArtDerSequenzierung::Keine,
+            ArtDerSequenzierung::Wgs,
+            ArtDerSequenzierung::Wes,
+            ArtDerSequenzierung::Panel,
+            ArtDerSequenzierung::WgsLr,
+        ]
+    }
+}
+
+impl RawValue for ArtDerSequenzierung {
+    fn raw_value(&self) -> &str {
+        match self {
+            ArtDerSequenzierung::Keine => "0",
+            ArtDerSequenzierung::Wgs => "1",
+            ArtDerSequenzierung::Wes => "2",
+            ArtDerSequenzierung::Panel => "3",
+            ArtDerSequenzierung::WgsLr => "4",
+            ArtDerSequenzierung::Unknown(u) => u,
+        }
+    }
+}
+
+impl Display for ArtDerSequenzierung {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArtDerSequenzierung::Keine => write!(f, "Keine"),
+            ArtDerSequenzierung::Wgs => write!(f, "WGS"),
+            ArtDerSequenzierung::Wes => write!(f, "WES"),
+            ArtDerSequenzierung::Panel => write!(f, "Panel"),
+            ArtDerSequenzierung::WgsLr => write!(f, "WGS/LR"),
+            ArtDerSequenzierung::Unknown(u) => write!(f, "Unbekannter Wert: '{u}'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_parse() {
+        let parsed = SubmissionSummary::from_str("Vorgangsnummer,Meldebestaetigung\nbad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31").unwrap();
+
+        assert_eq!(
+            parsed.tan.to_string(),
+            "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31"
+        );
+        assert_eq!(parsed.code.to_string(), "A123456789");
+        assert_eq!(parsed.date.to_string(), "2024-07-01");
+        assert_eq!(parsed.counter.to_string(), "001");
+        assert_eq!(parsed.ik, Ik::Ik260530103);
+        assert_eq!(
+            parsed.datacenter,
+            Datacenter::Unknown("KDKK00001".to_string())
+        );
+        assert_eq!(parsed.typ_der_meldung, TypDerMeldung::Erstmeldung);
+        assert_eq!(parsed.indikationsbereich, Indikationsbereich::O);
+        assert_eq!(parsed.kostentraeger, Kostentraeger::Gkv);
+        assert_eq!(parsed.art_der_daten, ArtDerDaten::C);
+        assert_eq!(parsed.art_der_sequenzierung, ArtDerSequenzierung::Wes);
+        assert!(parsed.accepted);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_build_summary() {
+        let summary = SubmissionSummaryBuilder::default()
+            .tan("bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31")
+            .code("A123456789")
+            .date("2024-07-01")
+            .ik(&Ik::Ik260530103)
+            .datacenter(&Datacenter::GRZK00001)
+            .indikationsbereich(&Indikationsbereich::O)
+            .kostentraeger(&Kostentraeger::Gkv)
+            .art_der_daten(&ArtDerDaten::G)
+            .art_der_sequenzierung(&ArtDerSequenzierung::Wes)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            summary.line(),
+            "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&GRZK00001&0&O&9&1&G&2&1+9+56b8dfc01cdccd02e4455555a97561e4797d3256be0710673931951ec7b41a46"
+        );
+        assert!(summary.is_valid());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_should_keep_values_of_parsed_summary() {
+        let line = "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701003&260530103&GRZK00001&0&O&8&1&G&2&0+7+7e5cfa7ba7e79a4b4d5ef5e8c1f72e4ce6ac18dff0ed5b77aaf6b6c1bc9a2e4d";
+        let parsed = SubmissionSummary::from_csv_or_line(line).unwrap();
+
+        let builder = SubmissionSummaryBuilder::from(&parsed);
+
+        assert_eq!(
+            builder.hash_string(),
+            "A123456789&20240701003&260530103&GRZK00001&0&O&8&1&G&2&0"
+        );
+        assert!(builder.line().contains("+7+"));
+        assert_eq!(
+            builder.kostentraeger(&Kostentraeger::Pkv).hash_string(),
+            "A123456789&20240701003&260530103&GRZK00001&0&O&8&2&G&2&0"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_valid_hash_validation() {
+        let parsed = SubmissionSummary::from_str("Vorgangsnummer,Meldebestaetigung\nbad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A123456789+A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31").unwrap();
+        assert!(parsed.valid_hash());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_invalid_hash_validation() {
+        let parsed = SubmissionSummary::from_str("Vorgangsnummer,Meldebestaetigung\nbad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31,IBE+A999999999+A999999999&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1+9+bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31").unwrap();
+        assert!(!parsed.valid_hash());
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            SubmissionSummary::hash("A123456789&20240701001&260530103&KDKK00001&0&O&9&1&C&2&1"),
+            "bad8a31b1759b565bee3d283e68af38e173499bfcce2f50691e7eddda62b2f31"
+        );
+    }
+
+    #[test]
+    fn test_raw_values_should_be_parsed_to_same_value() {
+        fn assert_roundtrip<T: FromStr + RawValue + PartialEq + std::fmt::Debug>(values: Vec<T>) {
+            for value in values {
+                assert_eq!(T::from_str(value.raw_value()).ok(), Some(value));
+            }
+        }
+
+        assert_roundtrip(Datacenter::values());
+        assert_roundtrip(Ik::values());
+        assert_roundtrip(TypDerMeldung::values());
+        assert_roundtrip(Indikationsbereich::values());
+        assert_roundtrip(Kostentraeger::values());
+        assert_roundtrip(ArtDerDaten::values());
+        assert_roundtrip(ArtDerSequenzierung::values());
+        assert_roundtrip(vec![Ik::Unknown("123456789".to_string())]);
+    }
+
+    #[rstest]
+    #[case("2026-01-01", true)]
+    #[case("1800-01-01", false)]
+    #[case("2100-01-01", false)]
+    #[case("2026-23-35", false)]
+    #[case("2026-1-1", false)]
+    #[case("2026-01-10", true)]
+    #[case("2026-10-12", true)]
+    #[case("2024-02-29", true)]
+    #[case("2026-13-01", false)]
+    #[case("2026-01-32", false)]
+    fn test_date_validation(#[case] date: &str, #[case] expected: bool) {
+        assert_eq!(SubmissionSummary::is_reasonable_date(date), expected);
+    }
+
+    #[rstest]
+    #[case("1", false)]
+    #[case("001", true)]
+    #[case("100", true)]
+    #[case("123", true)]
+    #[case("11", false)]
+    #[case("1234", false)]
+    fn test_number_validation(#[case] date: &str, #[case] expected: bool) {
+        assert_eq!(SubmissionSummary::matches_count_pattern(date), expected);
+    }
+
+    #[rstest]
+    #[case("202601011", "2026-01-01", "1")]
+    #[case("2026010112", "2026-01-01", "12")]
+    #[case("20260101123", "2026-01-01", "123")]
+    #[case("20260109001", "2026-01-09", "001")]
+    #[case("20260110001", "2026-01-10", "001")]
+    #[case("20260111123", "2026-01-11", "123")]
+    #[case("20260919123", "2026-09-19", "123")]
+    #[case("20261020123", "2026-10-20", "123")]
+    #[case("20261221123", "2026-12-21", "123")]
+    #[case("20261229123", "2026-12-29", "123")]
+    #[case("20261230123", "2026-12-30", "123")]
+    #[case("20261231123", "2026-12-31", "123")]
+    fn test_should_parse_date_and_number(
+        #[case] input: &str,
+        #[case] date: &str,
+        #[case] number: &str,
+    ) {
+        assert_eq!(
+            SubmissionSummary::parse_date_and_number(input),
+            Some((date.to_string(), number.to_string()))
+        );
+    }
+
+    #[rstest]
+    fn test_should_not_parse_date_and_number(
+        #[values("20260101", "20260101123456789", "260101001", "", "irgendwas")] input: &str,
+    ) {
+        assert_eq!(SubmissionSummary::parse_date_and_number(input), None);
+    }
+
+    #[rstest]
+    #[case("", ParseError::MissingHeader)]
+    #[case("Vorgangsnummer,Meldebestaetigung\nkaputt", ParseError::InvalidLine)]
+    #[case(
+        "Vorgangsnummer,Meldebestaetigung\nTAN,XYZ+A+B+9+C",
+        ParseError::InvalidMeldebestaetigung
+    )]
+    #[case(
+        "Vorgangsnummer,Meldebestaetigung\nTAN,IBE+A+A&20240701001+9+C",
+        ParseError::InvalidHashString
+    )]
+    #[case(
+        "Vorgangsnummer,Meldebestaetigung\nTAN,IBE+A+A&2024&260530103&KDKK00001&0&O&9&1&C&2&1+9+C",
+        ParseError::InvalidDate
+    )]
+    fn test_should_return_parse_error(#[case] input: &str, #[case] expected: ParseError) {
+        assert_eq!(SubmissionSummary::from_str(input).err(), Some(expected));
+    }
+}